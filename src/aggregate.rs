@@ -0,0 +1,227 @@
+//! Rollups of hourly forecasts into calendar days.
+
+use crate::{units::Precipitation, Alert, Hourly, Main, Weather};
+use jiff::{civil::Date, tz::TimeZone, Span, Zoned};
+
+/// Summary of the hourly entries for one calendar day. Values are in the units of the request.
+#[derive(Debug)]
+pub struct DailyAggregate {
+    /// Calendar day in the requested time zone
+    pub date: Date,
+
+    /// Number of hourly entries in the day
+    pub hours: usize,
+
+    pub min_temperature: f64,
+    pub max_temperature: f64,
+    pub mean_temperature: f64,
+
+    /// Total rain and snow volume, mm
+    pub precipitation: f64,
+
+    /// Strongest wind gust, if any entry reported one
+    pub max_gust: Option<f64>,
+
+    /// Most frequent condition group, preferring the earliest on ties
+    pub condition: Option<Main>,
+}
+
+/// Roll `hourly` up into one entry per calendar day in `tz`. Entries are expected to be in order.
+pub fn daily_from_hourly(hourly: &[Hourly], tz: &TimeZone) -> Vec<DailyAggregate> {
+    let date = |x: &Hourly| x.dt.with_time_zone(tz.clone()).date();
+
+    hourly
+        .chunk_by(|a, b| date(a) == date(b))
+        .map(|day| {
+            let temps = day.iter().map(|x| x.temp);
+            DailyAggregate {
+                date: date(&day[0]),
+                hours: day.len(),
+                min_temperature: temps.clone().fold(f64::INFINITY, f64::min),
+                max_temperature: temps.clone().fold(f64::NEG_INFINITY, f64::max),
+                mean_temperature: temps.sum::<f64>() / day.len() as f64,
+                precipitation: day
+                    .iter()
+                    .map(|x| x.total_precipitation().millimetres())
+                    .sum(),
+                max_gust: day.iter().filter_map(|x| x.wind_gust).reduce(f64::max),
+                condition: dominant_condition(day),
+            }
+        })
+        .collect()
+}
+
+/// Forecast for the period of an event, as returned by [`Weather::for_event`]. Values are in
+/// the units of the request.
+#[derive(Debug)]
+pub struct EventForecast<'a> {
+    /// Hourly entries overlapping the event
+    pub hours: usize,
+
+    /// Lowest temperature, if any hourly entries overlap the event
+    pub min_temperature: Option<f64>,
+
+    /// Highest temperature, if any hourly entries overlap the event
+    pub max_temperature: Option<f64>,
+
+    /// Highest probability of precipitation, from 0 to 1
+    pub max_pop: f64,
+
+    /// Strongest wind gust, if any entry reported one
+    pub max_gust: Option<f64>,
+
+    /// Alerts in effect at any point during the event
+    pub alerts: Vec<&'a Alert>,
+}
+
+impl<'a> EventForecast<'a> {
+    pub(crate) fn new(weather: &'a Weather, start: &Zoned, end: &Zoned) -> Self {
+        let hourly: Vec<&Hourly> = weather
+            .hourly
+            .iter()
+            .flatten()
+            .filter(|x| x.dt < *end && *start < x.dt.saturating_add(Span::new().hours(1)))
+            .collect();
+        let temps = hourly.iter().map(|x| x.temp);
+
+        Self {
+            hours: hourly.len(),
+            min_temperature: temps.clone().reduce(f64::min),
+            max_temperature: temps.reduce(f64::max),
+            max_pop: hourly.iter().filter_map(|x| x.pop).fold(0.0, f64::max),
+            max_gust: hourly.iter().filter_map(|x| x.wind_gust).reduce(f64::max),
+            alerts: weather
+                .alerts
+                .iter()
+                .flatten()
+                .filter(|x| x.overlaps(start, end))
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn precipitation_next(weather: &Weather, span: Span) -> Precipitation {
+    let minutely = weather.minutely.as_deref().unwrap_or_default();
+    let hourly = weather.hourly.as_deref().unwrap_or_default();
+    let Some(start) = weather
+        .current
+        .as_ref()
+        .map(|x| &x.dt)
+        .or(minutely.first().map(|x| &x.dt))
+        .or(hourly.first().map(|x| &x.dt))
+    else {
+        return Precipitation(0.0);
+    };
+    // Spans past the end of the representable times cover the rest of the data
+    let end = start.saturating_add(span);
+
+    // Minutely intensities are mm/h, so each entry contributes a sixtieth of its value
+    let in_window = minutely.iter().filter(|x| *start <= x.dt && x.dt < end);
+    let from_minutely: f64 = in_window.map(|x| x.precipitation / 60.0).sum();
+
+    let hourly_start = match minutely.last() {
+        Some(last) => last
+            .dt
+            .saturating_add(Span::new().minutes(1))
+            .max(start.clone()),
+        None => start.clone(),
+    };
+    let from_hourly: f64 = hourly
+        .iter()
+        .map(|x| {
+            let hour_end = x.dt.saturating_add(Span::new().hours(1));
+            let overlap = hour_end.min(end.clone()).timestamp().as_second()
+                - x.dt
+                    .clone()
+                    .max(hourly_start.clone())
+                    .timestamp()
+                    .as_second();
+            overlap.max(0) as f64 / 3600.0 * x.total_precipitation().millimetres()
+        })
+        .sum();
+
+    Precipitation(from_minutely + from_hourly)
+}
+
+fn dominant_condition(day: &[Hourly]) -> Option<Main> {
+    let mut counts: Vec<(Main, usize)> = Vec::new();
+    for main in day.iter().filter_map(|x| x.weather.first()).map(|x| x.main) {
+        match counts.iter_mut().find(|(x, _)| *x == main) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((main, 1)),
+        }
+    }
+
+    // `max_by_key` keeps the last maximum, so search from the end to prefer the earliest
+    counts
+        .into_iter()
+        .rev()
+        .max_by_key(|(_, count)| *count)
+        .map(|(main, _)| main)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::Timestamp;
+
+    #[test]
+    fn aggregate_days() {
+        let weather = crate::fixtures::full();
+        let hourly = weather.hourly.unwrap();
+
+        let days = daily_from_hourly(&hourly, &TimeZone::fixed(jiff::tz::offset(-5)));
+        assert_eq!(1, days.len());
+        assert_eq!(6, days[0].hours);
+
+        // The last entry is past midnight two hours east of UTC
+        let days = daily_from_hourly(&hourly, &TimeZone::fixed(jiff::tz::offset(2)));
+        assert_eq!(vec![5, 1], days.iter().map(|x| x.hours).collect::<Vec<_>>());
+        assert!(days[0].min_temperature <= days[0].mean_temperature);
+        assert!(days[0].mean_temperature <= days[0].max_temperature);
+    }
+
+    #[test]
+    fn event_forecast() {
+        let weather = crate::fixtures::full();
+        let hourly = weather.hourly.as_ref().unwrap();
+
+        // Starts halfway through the second hour and ends at the start of the fourth
+        let start = &hourly[1].dt + Span::new().minutes(30);
+        let event = weather.for_event(&start, &hourly[3].dt);
+        assert_eq!(2, event.hours);
+        assert_eq!(0.81, event.max_pop);
+        assert_eq!(1, event.alerts.len());
+
+        let later = &hourly[5].dt + Span::new().days(1);
+        let event = weather.for_event(&later, &(&later + Span::new().hours(2)));
+        assert_eq!(0, event.hours);
+        assert_eq!(None, event.max_temperature);
+    }
+
+    #[test]
+    fn precipitation_next() {
+        let weather = crate::fixtures::full();
+
+        let minutes = weather.precipitation_next(Span::new().minutes(5));
+        assert!((minutes.millimetres() - (0.21 + 0.53) / 60.0).abs() < 1e-9);
+
+        // The minutely series covers the first 8 minutes, then the hourly entries take over.
+        // Only the first 20 minutes of the second hour are in the window.
+        let hour = weather.precipitation_next(Span::new().hours(1));
+        assert!((hour.millimetres() - (1.69 / 60.0 + 0.42 / 3.0)).abs() < 1e-9);
+
+        // Past the end of the representable times, the whole forecast is accumulated
+        let all = weather.precipitation_next(Span::new().days(30));
+        let forever = weather.precipitation_next(Span::new().years(19_000));
+        assert_eq!(all.millimetres(), forever.millimetres());
+
+        // Nor does a series that ends at the last representable time
+        let mut weather = weather;
+        let minutely = weather.minutely.as_mut().unwrap();
+        minutely.last_mut().unwrap().dt = Timestamp::MAX.to_zoned(TimeZone::UTC);
+        let expected: f64 = minutely.iter().map(|x| x.precipitation / 60.0).sum();
+        let end = weather.precipitation_next(Span::new().years(19_000));
+        assert!((end.millimetres() - expected).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,258 @@
+//! Typed views of weather alerts.
+
+use crate::Alert;
+use std::fmt;
+
+/// Type of severe weather, as listed in an alert's `tags`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AlertTag {
+    Wind,
+    ExtremeTemperature,
+    Fog,
+    SnowIce,
+    Thunderstorm,
+    Rain,
+    Flood,
+    CoastalEvent,
+    MarineEvent,
+    Avalanches,
+    FireWarning,
+    AirQuality,
+    Dust,
+    Tsunami,
+    OtherDangers,
+
+    /// A tag outside of the known vocabulary
+    Other(String),
+}
+
+impl AlertTag {
+    /// The tag as OWM spells it
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Wind => "Wind",
+            Self::ExtremeTemperature => "Extreme temperature value",
+            Self::Fog => "Fog",
+            Self::SnowIce => "Snow/Ice",
+            Self::Thunderstorm => "Thunderstorm",
+            Self::Rain => "Rain",
+            Self::Flood => "Flood",
+            Self::CoastalEvent => "Coastal event",
+            Self::MarineEvent => "Marine event",
+            Self::Avalanches => "Avalanches",
+            Self::FireWarning => "Fire warning",
+            Self::AirQuality => "Air quality",
+            Self::Dust => "Dust",
+            Self::Tsunami => "Tsunami",
+            Self::OtherDangers => "Other dangers",
+            Self::Other(s) => s,
+        }
+    }
+}
+
+impl From<&str> for AlertTag {
+    fn from(tag: &str) -> Self {
+        match tag {
+            "Wind" => Self::Wind,
+            "Extreme temperature value" => Self::ExtremeTemperature,
+            "Fog" => Self::Fog,
+            "Snow/Ice" => Self::SnowIce,
+            "Thunderstorm" => Self::Thunderstorm,
+            "Rain" => Self::Rain,
+            "Flood" => Self::Flood,
+            "Coastal event" => Self::CoastalEvent,
+            "Marine event" => Self::MarineEvent,
+            "Avalanches" => Self::Avalanches,
+            "Fire warning" => Self::FireWarning,
+            "Air quality" => Self::AirQuality,
+            "Dust" => Self::Dust,
+            "Tsunami" => Self::Tsunami,
+            "Other dangers" => Self::OtherDangers,
+            _ => Self::Other(tag.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for AlertTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Whether `a` and `b` are copies of the same alert, possibly from different responses
+pub(crate) fn same_alert(a: &Alert, b: &Alert) -> bool {
+    a.sender_name == b.sender_name && a.event == b.event && a.start == b.start && a.end == b.end
+}
+
+/// CAP severity of an alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Minor,
+    Moderate,
+    Severe,
+    Extreme,
+}
+
+/// CAP urgency of an alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Urgency {
+    Immediate,
+    Expected,
+    Future,
+    Past,
+}
+
+/// CAP certainty of an alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Certainty {
+    Observed,
+    Likely,
+    Possible,
+    Unlikely,
+}
+
+/// Structured details extracted from an alert's event name and description
+///
+/// Alert descriptions are free text whose format depends on the sender, so every field is a best
+/// effort and may be missing.
+#[derive(Debug, Default, PartialEq)]
+pub struct AlertDetails {
+    /// From a `Severity:` line, or else guessed from the event name ("warning" is severe,
+    /// "watch" is moderate, "advisory" is minor)
+    pub severity: Option<Severity>,
+
+    /// From an `Urgency:` line
+    pub urgency: Option<Urgency>,
+
+    /// From a `Certainty:` line
+    pub certainty: Option<Certainty>,
+
+    /// Affected period, from a `* WHEN...` section as used by the US National Weather Service
+    pub when: Option<String>,
+
+    /// Protective actions, from a `PRECAUTIONARY/PREPAREDNESS ACTIONS...` section as used by the
+    /// US National Weather Service
+    pub instructions: Option<String>,
+}
+
+impl AlertDetails {
+    pub(crate) fn parse(event: &str, description: &str) -> Self {
+        let field = |name: &str| {
+            description.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.trim()
+                    .eq_ignore_ascii_case(name)
+                    .then(|| value.trim().to_ascii_lowercase())
+            })
+        };
+
+        let severity = field("severity")
+            .and_then(|x| match x.as_str() {
+                "extreme" => Some(Severity::Extreme),
+                "severe" => Some(Severity::Severe),
+                "moderate" => Some(Severity::Moderate),
+                "minor" => Some(Severity::Minor),
+                _ => None,
+            })
+            .or_else(|| {
+                let event = event.to_ascii_lowercase();
+                if event.contains("emergency") {
+                    Some(Severity::Extreme)
+                } else if event.contains("warning") {
+                    Some(Severity::Severe)
+                } else if event.contains("watch") {
+                    Some(Severity::Moderate)
+                } else if event.contains("advisory") {
+                    Some(Severity::Minor)
+                } else {
+                    None
+                }
+            });
+        let urgency = field("urgency").and_then(|x| match x.as_str() {
+            "immediate" => Some(Urgency::Immediate),
+            "expected" => Some(Urgency::Expected),
+            "future" => Some(Urgency::Future),
+            "past" => Some(Urgency::Past),
+            _ => None,
+        });
+        let certainty = field("certainty").and_then(|x| match x.as_str() {
+            "observed" => Some(Certainty::Observed),
+            "likely" => Some(Certainty::Likely),
+            "possible" => Some(Certainty::Possible),
+            "unlikely" => Some(Certainty::Unlikely),
+            _ => None,
+        });
+
+        Self {
+            severity,
+            urgency,
+            certainty,
+            when: section(description, "* WHEN..."),
+            instructions: section(description, "PRECAUTIONARY/PREPAREDNESS ACTIONS..."),
+        }
+    }
+}
+
+/// Text following `heading`, up to the next bullet, blank line, or `&&` terminator
+fn section(description: &str, heading: &str) -> Option<String> {
+    let start = description.find(heading)? + heading.len();
+    let text = description[start..]
+        .lines()
+        .enumerate()
+        .take_while(|(i, line)| {
+            let line = line.trim();
+            *i == 0 || !(line.starts_with('*') || line.starts_with("&&"))
+        })
+        .map(|(_, line)| line.trim())
+        .skip_while(|line| line.is_empty())
+        .take_while(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!text.is_empty()).then_some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tags() {
+        assert_eq!(
+            AlertTag::ExtremeTemperature,
+            "Extreme temperature value".into()
+        );
+        assert_eq!(AlertTag::SnowIce, "Snow/Ice".into());
+        assert_eq!(AlertTag::Other("Heat".to_string()), AlertTag::from("Heat"));
+        assert_eq!("Heat", AlertTag::from("Heat").as_str());
+    }
+
+    #[test]
+    fn parse_details() {
+        let description = "* WHAT...Heat index values up to 108 expected.\n\n\
+            * WHERE...Portions of central Iowa.\n\n\
+            * WHEN...From noon to 8 PM CDT Sunday.\n\n\
+            PRECAUTIONARY/PREPAREDNESS ACTIONS...\n\n\
+            Drink plenty of fluids, stay in an air-conditioned room,\n\
+            and check up on relatives and neighbors.\n\n\
+            &&";
+        let details = AlertDetails::parse("Heat Advisory", description);
+        assert_eq!(Some(Severity::Minor), details.severity);
+        assert_eq!(
+            Some("From noon to 8 PM CDT Sunday."),
+            details.when.as_deref()
+        );
+        assert_eq!(
+            Some("Drink plenty of fluids, stay in an air-conditioned room, and check up on relatives and neighbors."),
+            details.instructions.as_deref()
+        );
+
+        let details = AlertDetails::parse(
+            "Hitze",
+            "Severity: Extreme\nUrgency: Immediate\nCertainty: Observed",
+        );
+        assert_eq!(Some(Severity::Extreme), details.severity);
+        assert_eq!(Some(Urgency::Immediate), details.urgency);
+        assert_eq!(Some(Certainty::Observed), details.certainty);
+        assert_eq!(None, details.when);
+    }
+}
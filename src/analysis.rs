@@ -0,0 +1,347 @@
+//! Analyses over forecast series.
+
+use crate::{Daily, Hourly, Minutely};
+use jiff::Zoned;
+
+/// Kind of extreme temperature event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpellKind {
+    /// Daily maximum temperature above the threshold
+    HeatWave,
+
+    /// Daily minimum temperature below the threshold
+    ColdSnap,
+}
+
+/// Definition of an extreme temperature event, such as "at least 3 days above 30 °C"
+#[derive(Debug, Clone, Copy)]
+pub struct SpellDefinition {
+    pub kind: SpellKind,
+
+    /// Temperature threshold, in the units of the request. See [`percentile`] to derive one from
+    /// past observations.
+    pub threshold: f64,
+
+    /// Minimum number of consecutive days past the threshold
+    pub min_days: usize,
+}
+
+/// A detected run of consecutive days past the threshold of a [`SpellDefinition`]
+#[derive(Debug)]
+pub struct TemperatureSpell {
+    pub kind: SpellKind,
+
+    /// Time of the first day of the event
+    pub start: Zoned,
+
+    /// Time of the last day of the event
+    pub end: Zoned,
+
+    /// Number of days in the event
+    pub days: usize,
+
+    /// Highest maximum (heat wave) or lowest minimum (cold snap) temperature during the event
+    pub peak: f64,
+}
+
+impl SpellDefinition {
+    pub fn heat_wave(threshold: f64, min_days: usize) -> Self {
+        Self {
+            kind: SpellKind::HeatWave,
+            threshold,
+            min_days,
+        }
+    }
+
+    pub fn cold_snap(threshold: f64, min_days: usize) -> Self {
+        Self {
+            kind: SpellKind::ColdSnap,
+            threshold,
+            min_days,
+        }
+    }
+
+    /// Find all events in `days`, which are expected to be consecutive and in order
+    pub fn detect(&self, days: &[Daily]) -> Vec<TemperatureSpell> {
+        let temp = |day: &Daily| match self.kind {
+            SpellKind::HeatWave => day.temp.max,
+            SpellKind::ColdSnap => day.temp.min,
+        };
+        let is_extreme = |day: &Daily| match self.kind {
+            SpellKind::HeatWave => temp(day) > self.threshold,
+            SpellKind::ColdSnap => temp(day) < self.threshold,
+        };
+
+        days.split(|day| !is_extreme(day))
+            .filter(|run| !run.is_empty() && run.len() >= self.min_days)
+            .map(|run| {
+                let temps = run.iter().map(temp);
+                TemperatureSpell {
+                    kind: self.kind,
+                    start: run[0].dt.clone(),
+                    end: run[run.len() - 1].dt.clone(),
+                    days: run.len(),
+                    peak: match self.kind {
+                        SpellKind::HeatWave => temps.fold(f64::NEG_INFINITY, f64::max),
+                        SpellKind::ColdSnap => temps.fold(f64::INFINITY, f64::min),
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+/// Whether a day had precipitation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreakKind {
+    Dry,
+    Wet,
+}
+
+/// A run of consecutive dry or wet days
+#[derive(Debug)]
+pub struct Streak {
+    pub kind: StreakKind,
+
+    /// Time of the first day of the streak
+    pub start: Zoned,
+
+    /// Time of the last day of the streak
+    pub end: Zoned,
+
+    /// Number of days in the streak
+    pub days: usize,
+}
+
+/// Split `days` into consecutive dry and wet streaks. A day is wet when its combined rain and
+/// snow volume is at least `threshold` mm.
+///
+/// `days` are expected to be consecutive and in order. To count a streak across past and
+/// forecasted days, chain your own stored days with [`Weather::daily`](crate::Weather::daily)
+/// before calling this.
+pub fn precipitation_streaks(days: &[Daily], threshold: f64) -> Vec<Streak> {
+    let kind = |day: &Daily| {
+        if day.total_precipitation().millimetres() >= threshold {
+            StreakKind::Wet
+        } else {
+            StreakKind::Dry
+        }
+    };
+
+    days.chunk_by(|a, b| kind(a) == kind(b))
+        .map(|run| Streak {
+            kind: kind(&run[0]),
+            start: run[0].dt.clone(),
+            end: run[run.len() - 1].dt.clone(),
+            days: run.len(),
+        })
+        .collect()
+}
+
+/// A change in precipitation within a minutely forecast
+#[derive(Debug, PartialEq)]
+pub enum NowcastEvent {
+    /// Precipitation starts at this minute
+    Starts(Zoned),
+
+    /// Precipitation stops at this minute
+    Stops(Zoned),
+}
+
+/// Summary of a minutely forecast, such as "rain starting in 12 minutes, stopping 20 minutes
+/// later"
+#[derive(Debug)]
+pub struct Nowcast {
+    /// Whether it's precipitating at the first minute
+    pub precipitating: bool,
+
+    /// Starts and stops of precipitation after the first minute, in order
+    pub events: Vec<NowcastEvent>,
+
+    /// Total expected precipitation over the whole series, mm
+    pub total: f64,
+}
+
+impl Nowcast {
+    /// Summarize `series`, which is expected to be one entry per minute and in order (see
+    /// [`minutely::repair`](crate::minutely::repair)). A minute counts as precipitating when its
+    /// intensity is at least `threshold` mm/h.
+    pub fn new(series: &[Minutely], threshold: f64) -> Self {
+        let is_wet = |x: &Minutely| x.precipitation >= threshold;
+
+        let events = series
+            .windows(2)
+            .filter_map(|pair| match (is_wet(&pair[0]), is_wet(&pair[1])) {
+                (false, true) => Some(NowcastEvent::Starts(pair[1].dt.clone())),
+                (true, false) => Some(NowcastEvent::Stops(pair[1].dt.clone())),
+                _ => None,
+            })
+            .collect();
+
+        Self {
+            precipitating: series.first().is_some_and(is_wet),
+            events,
+            total: series.iter().map(|x| x.precipitation / 60.0).sum(),
+        }
+    }
+
+    /// When precipitation next starts, if it isn't already precipitating
+    pub fn next_start(&self) -> Option<&Zoned> {
+        self.events.iter().find_map(|x| match x {
+            NowcastEvent::Starts(dt) if !self.precipitating => Some(dt),
+            _ => None,
+        })
+    }
+}
+
+/// Direction of a [`PressureTrend`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PressureTendency {
+    Rising,
+    Falling,
+
+    /// Changing by less than 1 hPa per 3 hours
+    Steady,
+}
+
+/// Barometric tendency over a series of hourly entries
+#[derive(Debug)]
+pub struct PressureTrend {
+    pub tendency: PressureTendency,
+
+    /// Rate of change, hPa per 3 hours
+    pub rate: f64,
+}
+
+/// Least squares trend of the sea level pressure in `hourly`. Returns `None` with fewer than two
+/// entries at different times.
+///
+/// A pressure falling by several hPa per 3 hours is a classic sign of an approaching storm.
+pub fn pressure_trend(hourly: &[Hourly]) -> Option<PressureTrend> {
+    let first = hourly.first()?.dt.timestamp().as_second();
+    let points: Vec<(f64, f64)> = hourly
+        .iter()
+        .map(|x| {
+            let hours = (x.dt.timestamp().as_second() - first) as f64 / 3600.0;
+            (hours, f64::from(x.pressure))
+        })
+        .collect();
+
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let sxx: f64 = points.iter().map(|(x, _)| (x - mean_x).powi(2)).sum();
+    let sxy: f64 = points
+        .iter()
+        .map(|(x, y)| (x - mean_x) * (y - mean_y))
+        .sum();
+    if sxx == 0.0 {
+        return None;
+    }
+
+    let rate = sxy / sxx * 3.0;
+    let tendency = if rate >= 1.0 {
+        PressureTendency::Rising
+    } else if rate <= -1.0 {
+        PressureTendency::Falling
+    } else {
+        PressureTendency::Steady
+    };
+    Some(PressureTrend { tendency, rate })
+}
+
+/// The `p`th percentile (0 to 100) of `values`, using linear interpolation between the closest
+/// ranks. Returns `None` if `values` is empty.
+pub fn percentile(values: &[f64], p: f64) -> Option<f64> {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    let rank = p.clamp(0.0, 100.0) / 100.0 * (sorted.len().checked_sub(1)? as f64);
+    let (lower, upper) = (sorted[rank.floor() as usize], sorted[rank.ceil() as usize]);
+    Some(lower + (upper - lower) * rank.fract())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_spells() {
+        let weather = crate::fixtures::full();
+        let daily = weather.daily.unwrap();
+
+        let spells = SpellDefinition::heat_wave(25.0, 2).detect(&daily);
+        assert_eq!(1, spells.len());
+        assert_eq!(SpellKind::HeatWave, spells[0].kind);
+        assert_eq!(3, spells[0].days);
+        assert_eq!(28.4, spells[0].peak);
+
+        assert!(SpellDefinition::heat_wave(27.0, 2)
+            .detect(&daily)
+            .is_empty());
+        assert!(SpellDefinition::cold_snap(0.0, 1).detect(&daily).is_empty());
+
+        assert_eq!(Some(2.5), percentile(&[4.0, 1.0, 3.0, 2.0], 50.0));
+        assert_eq!(None, percentile(&[], 90.0));
+    }
+
+    #[test]
+    fn streaks() {
+        let weather = crate::fixtures::full();
+        let daily = weather.daily.unwrap();
+
+        let streaks = precipitation_streaks(&daily, 0.2);
+        let kinds: Vec<_> = streaks.iter().map(|x| (x.kind, x.days)).collect();
+        assert_eq!(
+            vec![
+                (StreakKind::Wet, 1),
+                (StreakKind::Dry, 1),
+                (StreakKind::Wet, 1)
+            ],
+            kinds
+        );
+
+        let streaks = precipitation_streaks(&daily, 2.0);
+        assert_eq!(2, streaks[1].days);
+    }
+
+    #[test]
+    fn nowcast() {
+        let weather = crate::fixtures::full();
+        let minutely = weather.minutely.unwrap();
+
+        let nowcast = Nowcast::new(&minutely, 0.1);
+        assert!(!nowcast.precipitating);
+        assert_eq!(
+            vec![
+                NowcastEvent::Starts(minutely[3].dt.clone()),
+                NowcastEvent::Stops(minutely[7].dt.clone())
+            ],
+            nowcast.events
+        );
+        assert_eq!(Some(&minutely[3].dt), nowcast.next_start());
+        assert!((nowcast.total - 1.69 / 60.0).abs() < 1e-9);
+
+        let nowcast = Nowcast::new(&minutely[4..], 0.5);
+        assert!(nowcast.precipitating);
+        assert_eq!(None, nowcast.next_start());
+    }
+
+    #[test]
+    fn pressure() {
+        let weather = crate::fixtures::full();
+        let hourly = weather.hourly.unwrap();
+
+        let trend = pressure_trend(&hourly).unwrap();
+        assert_eq!(PressureTendency::Rising, trend.tendency);
+        assert!((trend.rate - 8.5 / 17.5 * 3.0).abs() < 1e-9);
+
+        let trend = pressure_trend(&hourly[..3]).unwrap();
+        assert_eq!(PressureTendency::Falling, trend.tendency);
+        assert_eq!(
+            PressureTendency::Steady,
+            pressure_trend(&hourly[1..3]).unwrap().tendency
+        );
+        assert!(pressure_trend(&hourly[..1]).is_none());
+    }
+}
@@ -0,0 +1,105 @@
+//! API key handling that keeps the key out of logs.
+
+use serde::{Deserialize, Deserializer};
+use std::{env, fmt};
+
+/// Environment variables checked by [`ApiKey::from_env`], in order
+pub const ENV_VARS: [&str; 2] = ["OWM_API_KEY", "OPENWEATHERMAP_API_KEY"];
+
+/// An OpenWeatherMap API key
+///
+/// `Debug` and `Display` print `REDACTED` instead of the key, so it can't end up in logs by
+/// accident. Use [`expose`](Self::expose) where the key is actually needed, such as when building
+/// a request URL. With the `zeroize` feature, the key is wiped from memory when dropped.
+#[derive(Clone, PartialEq, Eq)]
+pub struct ApiKey(String);
+
+impl ApiKey {
+    pub fn new(key: impl Into<String>) -> Self {
+        Self(key.into())
+    }
+
+    /// The key itself
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// Read the key from the first non-empty variable of [`ENV_VARS`]
+    pub fn from_env() -> Option<Self> {
+        Self::from_vars(&ENV_VARS)
+    }
+
+    fn from_vars(names: &[&str]) -> Option<Self> {
+        names
+            .iter()
+            .filter_map(|name| env::var(name).ok())
+            .find(|key| !key.trim().is_empty())
+            .map(|key| Self(key.trim().to_string()))
+    }
+}
+
+impl<'de> Deserialize<'de> for ApiKey {
+    fn deserialize<D>(d: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        String::deserialize(d).map(Self)
+    }
+}
+
+impl From<String> for ApiKey {
+    fn from(key: String) -> Self {
+        Self(key)
+    }
+}
+
+impl From<&str> for ApiKey {
+    fn from(key: &str) -> Self {
+        Self(key.to_string())
+    }
+}
+
+impl fmt::Debug for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ApiKey").field(&"REDACTED").finish()
+    }
+}
+
+impl fmt::Display for ApiKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        "REDACTED".fmt(f)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for ApiKey {
+    fn drop(&mut self) {
+        zeroize::Zeroize::zeroize(&mut self.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn redact() {
+        let key = ApiKey::from("abc123");
+        assert_eq!("REDACTED", key.to_string());
+        assert_eq!(r#"ApiKey("REDACTED")"#, format!("{key:?}"));
+        assert_eq!("abc123", key.expose());
+    }
+
+    #[test]
+    fn from_env() {
+        env::set_var("OWM_RS_TEST_EMPTY_KEY", " ");
+        env::set_var("OWM_RS_TEST_KEY", "abc123\n");
+        let names = [
+            "OWM_RS_TEST_MISSING_KEY",
+            "OWM_RS_TEST_EMPTY_KEY",
+            "OWM_RS_TEST_KEY",
+        ];
+        assert_eq!(Some(ApiKey::from("abc123")), ApiKey::from_vars(&names));
+        assert_eq!(None, ApiKey::from_vars(&names[..2]));
+    }
+}
@@ -0,0 +1,191 @@
+//! Conversion of hourly and daily forecasts to [Arrow](https://docs.rs/arrow) record batches,
+//! for columnar analytics.
+//!
+//! The batches have the same columns, in the same order, as the CSV
+//! [`export`](crate::export). Times are `Timestamp(Second, "UTC")` columns, and values missing
+//! from the response are nulls.
+
+use crate::{
+    export::{DAILY_COLUMNS, HOURLY_COLUMNS},
+    Daily, Hourly, WeatherElement,
+};
+use arrow_array::{
+    ArrayRef, Float64Array, Int64Array, RecordBatch, StringArray, TimestampSecondArray,
+    UInt16Array, UInt32Array, UInt8Array,
+};
+use arrow_schema::{ArrowError, Field, Schema};
+use jiff::Zoned;
+use std::sync::Arc;
+
+/// Record batch of hourly forecasts, with the columns of
+/// [`HOURLY_COLUMNS`]
+pub fn hourly_batch(hourly: &[Hourly]) -> Result<RecordBatch, ArrowError> {
+    let mut columns = Columns::default();
+    columns.times(hourly.iter().map(|x| Some(&x.dt)), false);
+    columns.floats(hourly.iter().map(|x| Some(x.temp)), false);
+    columns.floats(hourly.iter().map(|x| Some(x.feels_like)), false);
+    columns.push(
+        UInt16Array::from_iter_values(hourly.iter().map(|x| x.pressure)),
+        false,
+    );
+    columns.push(
+        UInt8Array::from_iter_values(hourly.iter().map(|x| x.humidity)),
+        false,
+    );
+    columns.floats(hourly.iter().map(|x| Some(x.dew_point)), false);
+    columns.floats(hourly.iter().map(|x| x.uvi), true);
+    columns.push(
+        UInt8Array::from_iter_values(hourly.iter().map(|x| x.clouds)),
+        false,
+    );
+    columns.push(
+        UInt32Array::from_iter(hourly.iter().map(|x| x.visibility)),
+        true,
+    );
+    columns.floats(hourly.iter().map(|x| Some(x.wind_speed)), false);
+    columns.floats(hourly.iter().map(|x| x.wind_gust), true);
+    columns.push(
+        UInt16Array::from_iter_values(hourly.iter().map(|x| x.wind_deg)),
+        false,
+    );
+    columns.floats(hourly.iter().map(|x| x.pop), true);
+    columns.floats(
+        hourly.iter().map(|x| x.rain.as_ref().map(|x| x.volume().0)),
+        true,
+    );
+    columns.floats(
+        hourly.iter().map(|x| x.snow.as_ref().map(|x| x.volume().0)),
+        true,
+    );
+    columns.conditions(hourly.iter().map(|x| &x.weather[..]));
+    columns.batch(HOURLY_COLUMNS)
+}
+
+/// Record batch of daily forecasts, with the columns of
+/// [`DAILY_COLUMNS`]
+pub fn daily_batch(daily: &[Daily]) -> Result<RecordBatch, ArrowError> {
+    let mut columns = Columns::default();
+    columns.times(daily.iter().map(|x| Some(&x.dt)), false);
+    columns.times(daily.iter().map(|x| x.sunrise.as_ref()), true);
+    columns.times(daily.iter().map(|x| x.sunset.as_ref()), true);
+    columns.times(daily.iter().map(|x| Some(&x.moonrise)), false);
+    columns.times(daily.iter().map(|x| Some(&x.moonset)), false);
+    columns.floats(daily.iter().map(|x| Some(x.moon_phase)), false);
+    columns.floats(daily.iter().map(|x| Some(x.temp.min)), false);
+    columns.floats(daily.iter().map(|x| Some(x.temp.max)), false);
+    columns.floats(daily.iter().map(|x| Some(x.temp.morn)), false);
+    columns.floats(daily.iter().map(|x| Some(x.temp.day)), false);
+    columns.floats(daily.iter().map(|x| Some(x.temp.eve)), false);
+    columns.floats(daily.iter().map(|x| Some(x.temp.night)), false);
+    columns.floats(daily.iter().map(|x| Some(x.feels_like.morn)), false);
+    columns.floats(daily.iter().map(|x| Some(x.feels_like.day)), false);
+    columns.floats(daily.iter().map(|x| Some(x.feels_like.eve)), false);
+    columns.floats(daily.iter().map(|x| Some(x.feels_like.night)), false);
+    columns.push(
+        UInt16Array::from_iter_values(daily.iter().map(|x| x.pressure)),
+        false,
+    );
+    columns.push(
+        UInt8Array::from_iter_values(daily.iter().map(|x| x.humidity)),
+        false,
+    );
+    columns.floats(daily.iter().map(|x| Some(x.dew_point)), false);
+    columns.floats(daily.iter().map(|x| Some(x.wind_speed)), false);
+    columns.floats(daily.iter().map(|x| x.wind_gust), true);
+    columns.push(
+        UInt16Array::from_iter_values(daily.iter().map(|x| x.wind_deg)),
+        false,
+    );
+    columns.push(
+        UInt8Array::from_iter_values(daily.iter().map(|x| x.clouds)),
+        false,
+    );
+    columns.floats(daily.iter().map(|x| Some(x.uvi)), false);
+    columns.floats(daily.iter().map(|x| x.pop), true);
+    columns.floats(daily.iter().map(|x| x.rain), true);
+    columns.floats(daily.iter().map(|x| x.snow), true);
+    columns.conditions(daily.iter().map(|x| &x.weather[..]));
+    columns.push(
+        StringArray::from_iter(daily.iter().map(|x| x.summary.as_deref())),
+        true,
+    );
+    columns.batch(DAILY_COLUMNS)
+}
+
+/// Arrays of a record batch, in column order, with whether each is nullable
+#[derive(Default)]
+struct Columns(Vec<(ArrayRef, bool)>);
+
+impl Columns {
+    fn push(&mut self, array: impl arrow_array::Array + 'static, nullable: bool) {
+        self.0.push((Arc::new(array), nullable));
+    }
+
+    fn floats(&mut self, values: impl Iterator<Item = Option<f64>>, nullable: bool) {
+        self.push(Float64Array::from_iter(values), nullable);
+    }
+
+    fn times<'a>(&mut self, values: impl Iterator<Item = Option<&'a Zoned>>, nullable: bool) {
+        let seconds = values.map(|x| Some(x?.timestamp().as_second()));
+        self.push(
+            TimestampSecondArray::from_iter(seconds).with_timezone("UTC"),
+            nullable,
+        );
+    }
+
+    /// Id, group, and description of each entry's primary condition
+    fn conditions<'a>(&mut self, weather: impl Iterator<Item = &'a [WeatherElement]> + Clone) {
+        let first = weather.map(|x| x.first());
+        self.push(
+            Int64Array::from_iter(first.clone().map(|x| Some(x?.id))),
+            true,
+        );
+        self.push(
+            StringArray::from_iter(first.clone().map(|x| Some(format!("{:?}", x?.main)))),
+            true,
+        );
+        self.push(
+            StringArray::from_iter(first.map(|x| Some(x?.description.as_str()))),
+            true,
+        );
+    }
+
+    fn batch(self, names: &[&str]) -> Result<RecordBatch, ArrowError> {
+        let fields: Vec<Field> = names
+            .iter()
+            .zip(&self.0)
+            .map(|(name, (array, nullable))| {
+                Field::new(*name, array.data_type().clone(), *nullable)
+            })
+            .collect();
+        let arrays = self.0.into_iter().map(|x| x.0).collect();
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow_array::Array;
+
+    #[test]
+    fn record_batches() {
+        let weather = crate::fixtures::full();
+        let hourly = weather.hourly.unwrap();
+        let batch = hourly_batch(&hourly).unwrap();
+        assert_eq!(hourly.len(), batch.num_rows());
+        assert_eq!(HOURLY_COLUMNS.len(), batch.num_columns());
+
+        let dt = batch["dt"]
+            .as_any()
+            .downcast_ref::<TimestampSecondArray>()
+            .unwrap();
+        assert_eq!(hourly[0].dt.timestamp().as_second(), dt.value(0));
+        assert!(batch["rain"].is_null(0));
+
+        let daily = weather.daily.unwrap();
+        let batch = daily_batch(&daily).unwrap();
+        assert_eq!(daily.len(), batch.num_rows());
+        assert_eq!(DAILY_COLUMNS.len(), batch.num_columns());
+    }
+}
@@ -0,0 +1,90 @@
+//! Helpers for aviation weather briefings.
+//!
+//! OWM reports `pressure` reduced to sea level (QNH-like). The pressure helpers convert it to the
+//! pressure at a given elevation (QFE) and back, and compute pressure altitude, based on the ICAO
+//! standard atmosphere.
+
+/// Standard sea level pressure, hPa
+pub const STANDARD_PRESSURE: f64 = 1013.25;
+
+/// Standard sea level temperature, kelvin
+const STANDARD_TEMPERATURE: f64 = 288.15;
+
+/// Temperature lapse rate in the troposphere, kelvin per metre
+const LAPSE_RATE: f64 = 0.0065;
+
+/// Exponent of the barometric formula, `g·M / (R·L)`
+const BAROMETRIC_EXPONENT: f64 = 5.255_88;
+
+/// Pressure at `elevation` metres (QFE) given the sea level pressure (QNH), both in hPa.
+pub fn station_pressure(sea_level_pressure: f64, elevation: f64) -> f64 {
+    sea_level_pressure
+        * (1.0 - LAPSE_RATE * elevation / STANDARD_TEMPERATURE).powf(BAROMETRIC_EXPONENT)
+}
+
+/// Sea level pressure (QNH) given the pressure at `elevation` metres (QFE), both in hPa.
+pub fn sea_level_pressure(station_pressure: f64, elevation: f64) -> f64 {
+    station_pressure
+        / (1.0 - LAPSE_RATE * elevation / STANDARD_TEMPERATURE).powf(BAROMETRIC_EXPONENT)
+}
+
+/// Altitude in the standard atmosphere at which the pressure equals `station_pressure` (hPa), metres.
+pub fn pressure_altitude(station_pressure: f64) -> f64 {
+    STANDARD_TEMPERATURE / LAPSE_RATE
+        * (1.0 - (station_pressure / STANDARD_PRESSURE).powf(1.0 / BAROMETRIC_EXPONENT))
+}
+
+/// Likelihood of fog forming or persisting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FogRisk {
+    Low,
+    Moderate,
+    High,
+}
+
+/// Classify the fog risk from the dew point spread (°C), humidity (%), and visibility (metres)
+///
+/// Visibility below 1 km means fog is already present, and below 5 km means mist, which is
+/// treated as at least a moderate risk.
+pub fn fog_risk(spread: f64, humidity: u8, visibility: Option<u32>) -> FogRisk {
+    let from_air = if spread <= 1.0 && humidity >= 95 {
+        FogRisk::High
+    } else if spread <= 2.5 && humidity >= 90 {
+        FogRisk::Moderate
+    } else {
+        FogRisk::Low
+    };
+
+    let from_visibility = match visibility {
+        Some(x) if x < 1000 => FogRisk::High,
+        Some(x) if x < 5000 => FogRisk::Moderate,
+        _ => FogRisk::Low,
+    };
+
+    from_air.max(from_visibility)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip_station_pressure() {
+        let qfe = station_pressure(1020.0, 1500.0);
+        assert!((qfe - 851.2).abs() < 0.5, "{qfe}");
+        assert!((sea_level_pressure(qfe, 1500.0) - 1020.0).abs() < 1e-9);
+
+        // With standard pressure at sea level, pressure altitude is the elevation
+        let qfe = station_pressure(STANDARD_PRESSURE, 1500.0);
+        assert!((pressure_altitude(qfe) - 1500.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn classify_fog_risk() {
+        assert_eq!(FogRisk::High, fog_risk(0.5, 98, Some(10000)));
+        assert_eq!(FogRisk::Moderate, fog_risk(2.0, 92, None));
+        assert_eq!(FogRisk::Moderate, fog_risk(6.0, 60, Some(3000)));
+        assert_eq!(FogRisk::High, fog_risk(6.0, 60, Some(800)));
+        assert_eq!(FogRisk::Low, fog_risk(6.0, 60, Some(10000)));
+    }
+}
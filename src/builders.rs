@@ -0,0 +1,491 @@
+//! Builders for fabricating models in tests.
+//!
+//! Every builder starts from a realistic mild summer day, in metric units, so tests only need to
+//! set the fields they care about:
+//!
+//! ```
+//! use owm_rs::{Current, Hourly, Weather};
+//!
+//! let weather = Weather::builder()
+//!     .current(Current::builder().temp(-12.0).humidity(80).build())
+//!     .hourly(vec![Hourly::builder().pop(0.9).rain(2.5).build()])
+//!     .build();
+//! assert_eq!(Some(2.5), weather.hourly.unwrap()[0].rain.as_ref().and_then(|x| x.one_hour));
+//! ```
+
+use crate::{
+    Alert, Current, Daily, DailyFeelsLikeTemperature, DailyTemperature, Hourly, Main, Minutely,
+    Precipitation, Weather, WeatherElement,
+};
+use jiff::{Span, Timestamp, Zoned};
+
+/// 2024-07-21T17:00:00Z, the default time of every builder
+fn default_time() -> Zoned {
+    Timestamp::from_second(1_721_581_200)
+        .unwrap()
+        .to_zoned(jiff::tz::TimeZone::UTC)
+}
+
+fn volume(one_hour: f64) -> Precipitation {
+    Precipitation {
+        one_hour: Some(one_hour),
+        three_hours: None,
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    }
+}
+
+fn clear_sky() -> Vec<WeatherElement> {
+    vec![WeatherElement {
+        id: 800,
+        main: Main::Clear,
+        description: "clear sky".to_string(),
+        icon: "01d".to_string(),
+        #[cfg(feature = "extra-fields")]
+        extra: Default::default(),
+    }]
+}
+
+macro_rules! setters {
+    ($builder:ident { $($field:ident: $t:ty),* $(,)? }) => {
+        impl $builder {
+            $(
+                pub fn $field(mut self, value: $t) -> Self {
+                    self.0.$field = value;
+                    self
+                }
+            )*
+        }
+    };
+}
+
+macro_rules! condition_setter {
+    ($builder:ident) => {
+        impl $builder {
+            /// Replace the weather conditions with a single one
+            pub fn condition(mut self, id: i64, main: Main, description: &str, icon: &str) -> Self {
+                self.0.weather = vec![WeatherElement {
+                    id,
+                    main,
+                    description: description.to_string(),
+                    icon: icon.to_string(),
+                    #[cfg(feature = "extra-fields")]
+                    extra: Default::default(),
+                }];
+                self
+            }
+        }
+    };
+}
+
+/// Builder for [`Weather`]
+#[derive(Debug)]
+pub struct WeatherBuilder(Weather);
+
+impl Weather {
+    /// A response for 0° latitude and longitude, in UTC, with no sections
+    pub fn builder() -> WeatherBuilder {
+        WeatherBuilder(Weather {
+            lat: 0.0,
+            lon: 0.0,
+            timezone: "UTC".to_string(),
+            timezone_offset: 0,
+            current: None,
+            minutely: None,
+            hourly: None,
+            daily: None,
+            alerts: None,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl WeatherBuilder {
+    pub fn lat(mut self, value: f64) -> Self {
+        self.0.lat = value;
+        self
+    }
+
+    pub fn lon(mut self, value: f64) -> Self {
+        self.0.lon = value;
+        self
+    }
+
+    pub fn timezone(mut self, name: &str, offset: i32) -> Self {
+        self.0.timezone = name.to_string();
+        self.0.timezone_offset = offset;
+        self
+    }
+
+    pub fn current(mut self, value: Current) -> Self {
+        self.0.current = Some(value);
+        self
+    }
+
+    pub fn minutely(mut self, value: Vec<Minutely>) -> Self {
+        self.0.minutely = Some(value);
+        self
+    }
+
+    pub fn hourly(mut self, value: Vec<Hourly>) -> Self {
+        self.0.hourly = Some(value);
+        self
+    }
+
+    pub fn daily(mut self, value: Vec<Daily>) -> Self {
+        self.0.daily = Some(value);
+        self
+    }
+
+    pub fn alerts(mut self, value: Vec<Alert>) -> Self {
+        self.0.alerts = Some(value);
+        self
+    }
+
+    pub fn build(self) -> Weather {
+        self.0
+    }
+}
+
+/// Builder for [`Current`]
+#[derive(Debug)]
+pub struct CurrentBuilder(Current);
+
+impl Current {
+    /// 20 °C and clear, six hours after sunrise
+    pub fn builder() -> CurrentBuilder {
+        let dt = default_time();
+        CurrentBuilder(Current {
+            sunrise: Some(&dt - Span::new().hours(6)),
+            sunset: Some(&dt + Span::new().hours(6)),
+            dt,
+            temp: 20.0,
+            feels_like: 20.0,
+            pressure: 1013,
+            humidity: 50,
+            dew_point: 9.3,
+            clouds: 0,
+            uvi: 5.0,
+            visibility: Some(10000),
+            wind_speed: 3.0,
+            wind_gust: None,
+            wind_deg: 180,
+            rain: None,
+            snow: None,
+            weather: clear_sky(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+setters!(CurrentBuilder {
+    dt: Zoned,
+    sunrise: Option<Zoned>,
+    sunset: Option<Zoned>,
+    temp: f64,
+    feels_like: f64,
+    pressure: u16,
+    humidity: u8,
+    dew_point: f64,
+    clouds: u8,
+    uvi: f64,
+    visibility: Option<u32>,
+    wind_speed: f64,
+    wind_gust: Option<f64>,
+    wind_deg: u16,
+});
+condition_setter!(CurrentBuilder);
+
+impl CurrentBuilder {
+    /// Rain volume for the last hour, mm
+    pub fn rain(mut self, value: f64) -> Self {
+        self.0.rain = Some(volume(value));
+        self
+    }
+
+    /// Snow volume for the last hour, mm
+    pub fn snow(mut self, value: f64) -> Self {
+        self.0.snow = Some(volume(value));
+        self
+    }
+
+    pub fn build(self) -> Current {
+        self.0
+    }
+}
+
+/// Builder for [`Hourly`]
+#[derive(Debug)]
+pub struct HourlyBuilder(Hourly);
+
+impl Hourly {
+    /// 20 °C and clear, with no chance of precipitation
+    pub fn builder() -> HourlyBuilder {
+        HourlyBuilder(Hourly {
+            dt: default_time(),
+            temp: 20.0,
+            feels_like: 20.0,
+            pressure: 1013,
+            humidity: 50,
+            dew_point: 9.3,
+            uvi: Some(5.0),
+            clouds: 0,
+            visibility: Some(10000),
+            wind_speed: 3.0,
+            wind_gust: None,
+            wind_deg: 180,
+            pop: Some(0.0),
+            rain: None,
+            snow: None,
+            weather: clear_sky(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+setters!(HourlyBuilder {
+    dt: Zoned,
+    temp: f64,
+    feels_like: f64,
+    pressure: u16,
+    humidity: u8,
+    dew_point: f64,
+    uvi: Option<f64>,
+    clouds: u8,
+    visibility: Option<u32>,
+    wind_speed: f64,
+    wind_gust: Option<f64>,
+    wind_deg: u16,
+});
+condition_setter!(HourlyBuilder);
+
+impl HourlyBuilder {
+    /// Probability of precipitation, from 0 to 1
+    pub fn pop(mut self, value: f64) -> Self {
+        self.0.pop = Some(value);
+        self
+    }
+
+    /// Rain volume for the hour, mm
+    pub fn rain(mut self, value: f64) -> Self {
+        self.0.rain = Some(volume(value));
+        self
+    }
+
+    /// Snow volume for the hour, mm
+    pub fn snow(mut self, value: f64) -> Self {
+        self.0.snow = Some(volume(value));
+        self
+    }
+
+    pub fn build(self) -> Hourly {
+        self.0
+    }
+}
+
+/// Builder for [`Daily`]
+#[derive(Debug)]
+pub struct DailyBuilder(Daily);
+
+impl Daily {
+    /// A clear day from 12 to 24 °C, with a full moon
+    pub fn builder() -> DailyBuilder {
+        let dt = default_time();
+        DailyBuilder(Daily {
+            sunrise: Some(&dt - Span::new().hours(6)),
+            sunset: Some(&dt + Span::new().hours(6)),
+            moonrise: &dt + Span::new().hours(7),
+            moonset: &dt - Span::new().hours(5),
+            dt,
+            moon_phase: 0.5,
+            summary: None,
+            temp: DailyTemperature {
+                morn: 14.0,
+                day: 22.0,
+                eve: 20.0,
+                night: 15.0,
+                min: 12.0,
+                max: 24.0,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            },
+            feels_like: DailyFeelsLikeTemperature {
+                morn: 14.0,
+                day: 22.0,
+                eve: 20.0,
+                night: 15.0,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            },
+            pressure: 1013,
+            humidity: 50,
+            dew_point: 9.3,
+            wind_speed: 3.0,
+            wind_gust: None,
+            wind_deg: 180,
+            clouds: 0,
+            uvi: 7.0,
+            pop: Some(0.0),
+            rain: None,
+            snow: None,
+            weather: clear_sky(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+setters!(DailyBuilder {
+    dt: Zoned,
+    sunrise: Option<Zoned>,
+    sunset: Option<Zoned>,
+    moonrise: Zoned,
+    moonset: Zoned,
+    moon_phase: f64,
+    temp: DailyTemperature,
+    feels_like: DailyFeelsLikeTemperature,
+    pressure: u16,
+    humidity: u8,
+    dew_point: f64,
+    wind_speed: f64,
+    wind_gust: Option<f64>,
+    wind_deg: u16,
+    clouds: u8,
+    uvi: f64,
+});
+condition_setter!(DailyBuilder);
+
+impl DailyBuilder {
+    /// Set the minimum and maximum temperatures, leaving the rest as is
+    pub fn min_max(mut self, min: f64, max: f64) -> Self {
+        self.0.temp.min = min;
+        self.0.temp.max = max;
+        self
+    }
+
+    pub fn summary(mut self, value: &str) -> Self {
+        self.0.summary = Some(value.to_string());
+        self
+    }
+
+    /// Probability of precipitation, from 0 to 1
+    pub fn pop(mut self, value: f64) -> Self {
+        self.0.pop = Some(value);
+        self
+    }
+
+    /// Rain volume, mm
+    pub fn rain(mut self, value: f64) -> Self {
+        self.0.rain = Some(value);
+        self
+    }
+
+    /// Snow volume, mm
+    pub fn snow(mut self, value: f64) -> Self {
+        self.0.snow = Some(value);
+        self
+    }
+
+    pub fn build(self) -> Daily {
+        self.0
+    }
+}
+
+/// Builder for [`Alert`]
+#[derive(Debug)]
+pub struct AlertBuilder(Alert);
+
+impl Alert {
+    /// A six hour heat warning, without tags
+    pub fn builder() -> AlertBuilder {
+        let start = default_time();
+        AlertBuilder(Alert {
+            sender_name: "Environment Canada".to_string(),
+            event: "Heat Warning".to_string(),
+            end: &start + Span::new().hours(6),
+            start,
+            description: "Hot and humid conditions are expected.".to_string(),
+            tags: Vec::new(),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+setters!(AlertBuilder {
+    start: Zoned,
+    end: Zoned,
+});
+
+impl AlertBuilder {
+    pub fn sender_name(mut self, value: &str) -> Self {
+        self.0.sender_name = value.to_string();
+        self
+    }
+
+    pub fn event(mut self, value: &str) -> Self {
+        self.0.event = value.to_string();
+        self
+    }
+
+    pub fn description(mut self, value: &str) -> Self {
+        self.0.description = value.to_string();
+        self
+    }
+
+    pub fn tag(mut self, value: &str) -> Self {
+        self.0.tags.push(value.to_string());
+        self
+    }
+
+    pub fn build(self) -> Alert {
+        self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_weather() {
+        let start = default_time();
+        let hourly = (0..3)
+            .map(|i| {
+                Hourly::builder()
+                    .dt(&start + Span::new().hours(i))
+                    .temp(20.0 + i as f64)
+                    .build()
+            })
+            .collect();
+        let weather = Weather::builder()
+            .timezone("America/Winnipeg", -18000)
+            .hourly(hourly)
+            .daily(vec![Daily::builder().min_max(-5.0, 3.0).snow(4.0).build()])
+            .alerts(vec![Alert::builder()
+                .tag("Extreme temperature value")
+                .build()])
+            .build();
+
+        assert_eq!(
+            Vec::<crate::validate::ValidationIssue>::new(),
+            weather.validate()
+        );
+        assert_eq!(
+            3,
+            weather
+                .hours_between(&start, &(&start + Span::new().days(1)))
+                .count()
+        );
+        assert_eq!(
+            4.0,
+            weather.daily.as_ref().unwrap()[0]
+                .total_precipitation()
+                .millimetres()
+        );
+        assert_eq!(1, weather.alerts_active_at(&start).count());
+    }
+}
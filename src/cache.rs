@@ -0,0 +1,164 @@
+//! Reusing recent responses for nearby locations.
+//!
+//! A [`CacheStore`] returns a stored [`Weather`] for the same request until it expires. Stores
+//! are keyed by [`CacheKey`], whose coordinates are rounded to a number of decimal places so that
+//! points that round to the same coordinates share an entry: at two decimals, that's a cell of
+//! about a kilometre.
+//!
+//! [`MemoryCache`] is the in-process store. Implement [`CacheStore`] to keep responses elsewhere.
+
+use crate::{Units, Weather};
+use std::{
+    collections::HashMap,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// Request parameters that identify a cached response, with rounded coordinates
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct CacheKey {
+    lat: i64,
+    lon: i64,
+    decimals: u32,
+    pub units: Units,
+    pub lang: String,
+}
+
+impl CacheKey {
+    /// Key for a request, with coordinates rounded to `decimals` places
+    pub fn new(lat: f64, lon: f64, units: Units, lang: &str, decimals: u32) -> Self {
+        let scale = 10_f64.powi(decimals as i32);
+        Self {
+            lat: (lat * scale).round() as i64,
+            lon: (lon * scale).round() as i64,
+            decimals,
+            units,
+            lang: lang.to_string(),
+        }
+    }
+
+    /// Rounded latitude
+    pub fn lat(&self) -> f64 {
+        self.lat as f64 / 10_f64.powi(self.decimals as i32)
+    }
+
+    /// Rounded longitude
+    pub fn lon(&self) -> f64 {
+        self.lon as f64 / 10_f64.powi(self.decimals as i32)
+    }
+
+    /// FNV-1a hash of the key, stable across builds so it can name files
+    #[cfg(feature = "disk-cache")]
+    pub(crate) fn stable_hash(&self) -> u64 {
+        let text = format!(
+            "{},{},{},{:?},{}",
+            self.lat, self.lon, self.decimals, self.units, self.lang
+        );
+        text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+        })
+    }
+}
+
+/// Storage for responses that expire
+pub trait CacheStore {
+    /// The response stored for `key`, unless it's expired
+    fn get(&self, key: &CacheKey) -> Option<Arc<Weather>>;
+
+    /// Store a response for `key`, replacing any other, for `ttl`
+    fn put(&mut self, key: CacheKey, weather: Arc<Weather>, ttl: Duration);
+
+    /// Forget the response stored for `key`
+    fn invalidate(&mut self, key: &CacheKey);
+}
+
+/// In-process cache of responses
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    /// Responses with their expiry, or `None` if they never expire
+    entries: HashMap<CacheKey, (Option<Instant>, Arc<Weather>)>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop expired responses
+    pub fn purge(&mut self) {
+        let now = Instant::now();
+        self.entries.retain(|_, (expires, _)| fresh(*expires, now));
+    }
+}
+
+fn fresh(expires: Option<Instant>, now: Instant) -> bool {
+    expires.is_none_or(|x| now < x)
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &CacheKey) -> Option<Arc<Weather>> {
+        let weather = self
+            .entries
+            .get(key)
+            .filter(|(expires, _)| fresh(*expires, Instant::now()))
+            .map(|(_, weather)| Arc::clone(weather));
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            lat = key.lat(),
+            lon = key.lon(),
+            hit = weather.is_some(),
+            "memory cache lookup"
+        );
+        weather
+    }
+
+    fn put(&mut self, key: CacheKey, weather: Arc<Weather>, ttl: Duration) {
+        // A TTL too long to represent never expires
+        let expires = Instant::now().checked_add(ttl);
+        self.entries.insert(key, (expires, weather));
+    }
+
+    fn invalidate(&mut self, key: &CacheKey) {
+        self.entries.remove(key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearby_points() {
+        let weather = crate::fixtures::full();
+        let weather = Arc::new(weather);
+        let key = |lat, lon, units| CacheKey::new(lat, lon, units, "en", 2);
+        let ttl = Duration::from_secs(600);
+
+        let mut cache = MemoryCache::new();
+        cache.put(key(49.8951, -97.1384, Units::Metric), weather.clone(), ttl);
+        assert!(cache.get(&key(49.8972, -97.1381, Units::Metric)).is_some());
+        assert!(cache.get(&key(49.91, -97.1384, Units::Metric)).is_none());
+        assert!(cache
+            .get(&key(49.8951, -97.1384, Units::Imperial))
+            .is_none());
+
+        cache.invalidate(&key(49.8951, -97.1384, Units::Metric));
+        assert!(cache.get(&key(49.8951, -97.1384, Units::Metric)).is_none());
+
+        cache.put(key(0.0, 0.0, Units::Metric), weather, Duration::ZERO);
+        assert!(cache.get(&key(0.0, 0.0, Units::Metric)).is_none());
+        cache.purge();
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn long_ttl() {
+        let weather = crate::fixtures::full();
+        let key = CacheKey::new(49.8951, -97.1384, Units::Metric, "en", 2);
+
+        let mut cache = MemoryCache::new();
+        cache.put(key.clone(), Arc::new(weather), Duration::MAX);
+        cache.purge();
+        assert!(cache.get(&key).is_some());
+    }
+}
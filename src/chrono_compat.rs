@@ -0,0 +1,111 @@
+//! Conversions between the models' [`jiff`] times and [`chrono`](https://docs.rs/chrono).
+//!
+//! The models keep using [`Zoned`], since everything built on them does its arithmetic with
+//! jiff. Convert at the boundary instead:
+//!
+//! ```
+//! use owm_rs::chrono_compat::ToChrono;
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../fixtures/onecall.json"))?;
+//! let dt: chrono::DateTime<chrono::Utc> = weather.current.unwrap().dt.to_chrono_utc();
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use chrono::{DateTime, FixedOffset, TimeZone, Utc};
+use jiff::{tz, Timestamp, Zoned};
+
+/// Conversion of a jiff time to a chrono [`DateTime`]
+pub trait ToChrono {
+    /// The same instant, keeping the UTC offset
+    fn to_chrono(&self) -> DateTime<FixedOffset>;
+
+    /// The same instant in UTC
+    fn to_chrono_utc(&self) -> DateTime<Utc> {
+        self.to_chrono().to_utc()
+    }
+}
+
+impl ToChrono for Zoned {
+    fn to_chrono(&self) -> DateTime<FixedOffset> {
+        let offset = FixedOffset::east_opt(self.offset().seconds())
+            .expect("jiff offsets are less than a day");
+        self.timestamp().to_chrono().with_timezone(&offset)
+    }
+}
+
+impl ToChrono for Timestamp {
+    fn to_chrono(&self) -> DateTime<FixedOffset> {
+        // jiff's nanoseconds share the sign of the seconds, but chrono's count forward from the
+        // second before
+        let nanos = self.as_nanosecond();
+        let seconds = nanos.div_euclid(1_000_000_000) as i64;
+        let nanos = nanos.rem_euclid(1_000_000_000) as u32;
+        DateTime::from_timestamp(seconds, nanos)
+            .expect("jiff timestamps are within chrono's range")
+            .fixed_offset()
+    }
+}
+
+/// The same instant as a [`Zoned`] with `dt`'s UTC offset, or `None` if it's outside jiff's
+/// range
+pub fn to_zoned<Tz: TimeZone>(dt: &DateTime<Tz>) -> Option<Zoned> {
+    let dt = dt.fixed_offset();
+    let offset = tz::Offset::from_seconds(dt.offset().local_minus_utc()).ok()?;
+    let timestamp = Timestamp::new(dt.timestamp(), dt.timestamp_subsec_nanos() as i32).ok()?;
+    Some(timestamp.to_zoned(tz::TimeZone::fixed(offset)))
+}
+
+/// Serialize a `DateTime<Utc>` as a unix timestamp in seconds, the way OWM sends times
+///
+/// For fields of your own types that hold times taken from the models:
+/// `#[serde(with = "owm_rs::chrono_compat::ts_seconds")]`.
+pub mod ts_seconds {
+    use chrono::{DateTime, Utc};
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S>(dt: &DateTime<Utc>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_i64(dt.timestamp())
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<DateTime<Utc>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = i64::deserialize(d)?;
+        DateTime::from_timestamp(seconds, 0)
+            .ok_or_else(|| de::Error::custom(format!("invalid timestamp: {seconds}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let zoned: Zoned = "2024-07-01T06:30:15.5-05:00[America/Winnipeg]"
+            .parse()
+            .unwrap();
+        let dt = zoned.to_chrono();
+        assert_eq!("2024-07-01T06:30:15.500-05:00", dt.to_rfc3339());
+        assert_eq!(zoned.timestamp(), to_zoned(&dt).unwrap().timestamp());
+        assert_eq!(-5 * 3600, to_zoned(&dt).unwrap().offset().seconds());
+
+        #[derive(serde::Deserialize, serde::Serialize)]
+        struct Sample(#[serde(with = "ts_seconds")] DateTime<Utc>);
+
+        let x: Sample = serde_json::from_str("1719833415").unwrap();
+        assert_eq!(zoned.timestamp().as_second(), x.0.timestamp());
+        assert_eq!("1719833415", serde_json::to_string(&x).unwrap());
+    }
+
+    #[test]
+    fn before_epoch() {
+        let timestamp = Timestamp::new(-1, -500_000_000).unwrap();
+        let dt = timestamp.to_chrono_utc();
+        assert_eq!("1969-12-31T23:59:58.500+00:00", dt.to_rfc3339());
+        assert_eq!(timestamp, to_zoned(&dt).unwrap().timestamp());
+    }
+}
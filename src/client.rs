@@ -0,0 +1,517 @@
+//! An async client for the One Call API.
+//!
+//! A [`Client`] wires the rest of the crate's request handling together. Before each call it
+//! checks the [`CacheStore`] and the [`RateLimiter`], and it records the call in a
+//! [`QuotaTracker`]. It sends the validators kept by the [`ConditionalCache`], and it retries
+//! failures as its [`RetryPolicy`] allows. Responses are parsed with [`parse_response`].
+//!
+//! ```no_run
+//! # async fn run() -> Result<(), owm_rs::Error> {
+//! use owm_rs::{cache::MemoryCache, client::Client, rate_limit::Plan, ApiKey, Units};
+//! use std::time::Duration;
+//!
+//! let client = Client::builder(ApiKey::new("abc123"))
+//!     .units(Units::Metric)
+//!     .plan(Plan::free())
+//!     .cache(MemoryCache::new(), Duration::from_secs(600))
+//!     .build()?;
+//! let weather = client.one_call(49.8951, -97.1384).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use crate::{
+    cache::{CacheKey, CacheStore},
+    conditional::{ConditionalCache, Validators},
+    parse_response,
+    quota::QuotaTracker,
+    rate_limit::{Endpoint, Plan, RateLimiter},
+    retry::{self, Failure, RetryPolicy},
+    ApiKey, Error, Units, Weather,
+};
+use futures_util::{stream, Stream};
+use jiff::Timestamp;
+use reqwest::{header, StatusCode};
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+use tokio::time::{Interval, MissedTickBehavior};
+
+/// Where requests go unless [`ClientBuilder::base_url`] says otherwise
+pub const DEFAULT_BASE_URL: &str = "https://api.openweathermap.org";
+
+/// Decimal places that coordinates are rounded to for cache keys, about a kilometre
+pub const DEFAULT_CACHE_DECIMALS: u32 = 2;
+
+/// State shared by the calls of a [`Client`]
+struct State {
+    limiter: Option<RateLimiter>,
+    quota: QuotaTracker,
+    conditional: ConditionalCache,
+    cache: Option<(Box<dyn CacheStore + Send>, Duration)>,
+}
+
+/// Client for the One Call API
+///
+/// Calls can be made concurrently from a shared reference.
+pub struct Client {
+    http: reqwest::Client,
+    api_key: ApiKey,
+    base_url: String,
+    units: Units,
+    lang: String,
+    retry: RetryPolicy,
+    cache_decimals: u32,
+    state: Mutex<State>,
+}
+
+/// Settings for a [`Client`]
+pub struct ClientBuilder {
+    api_key: ApiKey,
+    base_url: String,
+    units: Units,
+    lang: String,
+    retry: RetryPolicy,
+    plan: Option<Plan>,
+    quota: Option<QuotaTracker>,
+    cache: Option<(Box<dyn CacheStore + Send>, Duration)>,
+    cache_decimals: u32,
+}
+
+impl ClientBuilder {
+    /// Units of the responses. Defaults to [`Units::Standard`], as OWM does.
+    pub fn units(mut self, units: Units) -> Self {
+        self.units = units;
+        self
+    }
+
+    /// Language of the condition descriptions, such as `"fr"`. Defaults to `"en"`.
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = lang.into();
+        self
+    }
+
+    /// Send requests to `url` instead of [`DEFAULT_BASE_URL`], such as a proxy or a mock server
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Retry failed calls as `policy` allows. Defaults to [`RetryPolicy::default`].
+    pub fn retry(mut self, policy: RetryPolicy) -> Self {
+        self.retry = policy;
+        self
+    }
+
+    /// Reject calls past `plan`'s limits with [`Error::RateLimitExhausted`] before they're sent.
+    /// Without a plan, calls aren't limited.
+    pub fn plan(mut self, plan: Plan) -> Self {
+        self.plan = Some(plan);
+        self
+    }
+
+    /// Count calls in `tracker`, such as one with counts persisted from an earlier run. Defaults
+    /// to a new tracker for the [`plan`](Self::plan).
+    pub fn quota(mut self, tracker: QuotaTracker) -> Self {
+        self.quota = Some(tracker);
+        self
+    }
+
+    /// Answer calls from `store` while a response for the same request is less than `ttl` old
+    pub fn cache(mut self, store: impl CacheStore + Send + 'static, ttl: Duration) -> Self {
+        self.cache = Some((Box::new(store), ttl));
+        self
+    }
+
+    /// Decimal places that coordinates are rounded to for cache keys and in logs. Defaults to
+    /// [`DEFAULT_CACHE_DECIMALS`].
+    pub fn cache_decimals(mut self, decimals: u32) -> Self {
+        self.cache_decimals = decimals;
+        self
+    }
+
+    pub fn build(self) -> Result<Client, Error> {
+        let http = reqwest::Client::builder()
+            .build()
+            .map_err(Error::transport)?;
+        let plan = self.plan.clone().unwrap_or_default();
+        Ok(Client {
+            http,
+            api_key: self.api_key,
+            base_url: self.base_url,
+            units: self.units,
+            lang: self.lang,
+            retry: self.retry,
+            cache_decimals: self.cache_decimals,
+            state: Mutex::new(State {
+                limiter: self.plan.map(RateLimiter::new),
+                quota: self.quota.unwrap_or_else(|| QuotaTracker::new(plan)),
+                conditional: ConditionalCache::new(),
+                cache: self.cache,
+            }),
+        })
+    }
+}
+
+impl Client {
+    /// Settings for a client that authenticates with `api_key`
+    pub fn builder(api_key: ApiKey) -> ClientBuilder {
+        ClientBuilder {
+            api_key,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            units: Units::default(),
+            lang: "en".to_string(),
+            retry: RetryPolicy::default(),
+            plan: None,
+            quota: None,
+            cache: None,
+            cache_decimals: DEFAULT_CACHE_DECIMALS,
+        }
+    }
+
+    /// Calls counted so far
+    pub fn quota(&self) -> QuotaTracker {
+        self.state().quota.clone()
+    }
+
+    /// Current weather and forecasts at `lat`, `lon`
+    pub async fn one_call(&self, lat: f64, lon: f64) -> Result<Arc<Weather>, Error> {
+        let key = CacheKey::new(lat, lon, self.units, &self.lang, self.cache_decimals);
+        #[cfg(feature = "tracing")]
+        let span = tracing::debug_span!("one_call", lat = key.lat(), lon = key.lon());
+        let call = self.fetch(Endpoint::OneCall, "/data/3.0/onecall", lat, lon, key);
+        #[cfg(feature = "tracing")]
+        let call = tracing::Instrument::instrument(call, span);
+        call.await
+    }
+
+    /// A stream of responses for `lat`, `lon`, fetched every `interval` starting now
+    ///
+    /// Responses without changes from the last one emitted, as [`Weather::diff`] compares them,
+    /// are skipped. Failed calls are emitted as errors and polling carries on. Polling stops when
+    /// the stream is dropped, so wrap it in a combinator such as `take_until` to stop on a
+    /// shutdown signal.
+    ///
+    /// # Panics
+    ///
+    /// If `interval` is zero.
+    pub fn watch(
+        &self,
+        lat: f64,
+        lon: f64,
+        interval: Duration,
+    ) -> impl Stream<Item = Result<Arc<Weather>, Error>> + '_ {
+        // The interval is made on the first poll, since it needs the runtime's timer
+        let state = (None::<Interval>, None::<Arc<Weather>>);
+        stream::unfold(state, move |(mut ticks, mut last)| async move {
+            let ticker = ticks.get_or_insert_with(|| {
+                let mut ticks = tokio::time::interval(interval);
+                ticks.set_missed_tick_behavior(MissedTickBehavior::Delay);
+                ticks
+            });
+            loop {
+                ticker.tick().await;
+                let weather = match self.one_call(lat, lon).await {
+                    Ok(weather) => weather,
+                    Err(e) => return Some((Err(e), (ticks, last))),
+                };
+                let unchanged = last.as_ref().is_some_and(|x| x.diff(&weather).is_empty());
+                if !unchanged {
+                    last = Some(Arc::clone(&weather));
+                    return Some((Ok(weather), (ticks, last)));
+                }
+            }
+        })
+    }
+
+    async fn fetch(
+        &self,
+        endpoint: Endpoint,
+        path: &str,
+        lat: f64,
+        lon: f64,
+        key: CacheKey,
+    ) -> Result<Arc<Weather>, Error> {
+        if let Some(weather) = self.cached(&key) {
+            return Ok(weather);
+        }
+        let units = match self.units {
+            Units::Standard => "standard",
+            Units::Metric => "metric",
+            Units::Imperial => "imperial",
+        };
+        // Validators are kept by this URL, which leaves out the API key
+        let url = format!(
+            "{}{path}?lat={lat}&lon={lon}&units={units}&lang={}",
+            self.base_url, self.lang
+        );
+
+        let start = Instant::now();
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            let (failure, retry_after, error) = match self.send(endpoint, &url).await? {
+                Response::Weather(weather) => {
+                    self.store(key, &weather);
+                    return Ok(weather);
+                }
+                Response::Failed {
+                    status,
+                    body,
+                    retry_after,
+                } => (
+                    Failure::Status(status),
+                    retry_after,
+                    Error::from_response(status, &body, retry_after),
+                ),
+                Response::Unsent(e) => (Failure::Connection, None, e),
+            };
+            let delay = self
+                .retry
+                .next_delay(failure, attempt, start.elapsed(), retry_after);
+            match delay {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            }
+        }
+    }
+
+    /// Make one attempt at a call. Errors that retrying can't fix are returned as `Err`.
+    async fn send(&self, endpoint: Endpoint, url: &str) -> Result<Response, Error> {
+        let validators = {
+            let mut state = self.state();
+            if let Some(limiter) = &mut state.limiter {
+                limiter
+                    .try_acquire(endpoint)
+                    .map_err(|wait| Error::RateLimitExhausted { wait })?;
+            }
+            state.quota.record(endpoint);
+            state
+                .conditional
+                .request_headers(url)
+                .into_iter()
+                .map(|(name, value)| (name, value.to_string()))
+                .collect::<Vec<_>>()
+        };
+
+        let mut request = self
+            .http
+            .get(format!("{url}&appid={}", self.api_key.expose()));
+        for (name, value) in validators {
+            request = request.header(name, value);
+        }
+        // Errors leave out the URL, which has the API key in it
+        let response = match request.send().await {
+            Ok(response) => response,
+            Err(e) => return Ok(Response::Unsent(Error::transport(e.without_url()))),
+        };
+
+        let status = response.status();
+        #[cfg(feature = "tracing")]
+        tracing::debug!(status = status.as_u16(), "response");
+        if status == StatusCode::NOT_MODIFIED {
+            if let Some(weather) = self.state().conditional.not_modified(url) {
+                return Ok(Response::Weather(weather));
+            }
+        }
+
+        let headers = response.headers().clone();
+        let value = |name: header::HeaderName| {
+            headers
+                .get(&name)
+                .and_then(|x| x.to_str().ok())
+                .map(|x| (name.as_str().to_string(), x.to_string()))
+        };
+        let validators = [value(header::ETAG), value(header::LAST_MODIFIED)];
+        let retry_after = value(header::RETRY_AFTER)
+            .and_then(|(_, x)| retry::parse_retry_after(&x, Timestamp::now()));
+
+        let body = match response.text().await {
+            Ok(body) => body,
+            Err(e) => return Ok(Response::Unsent(Error::transport(e.without_url()))),
+        };
+        if !status.is_success() {
+            return Ok(Response::Failed {
+                status: status.as_u16(),
+                body,
+                retry_after,
+            });
+        }
+
+        let weather = Arc::new(parse_response::<Weather>(&body)?);
+        let validators = Validators::from_headers(
+            validators
+                .iter()
+                .flatten()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+        );
+        self.state()
+            .conditional
+            .store(url, validators, Arc::clone(&weather));
+        Ok(Response::Weather(weather))
+    }
+
+    fn cached(&self, key: &CacheKey) -> Option<Arc<Weather>> {
+        let state = self.state();
+        let (store, _) = state.cache.as_ref()?;
+        store.get(key)
+    }
+
+    fn store(&self, key: CacheKey, weather: &Arc<Weather>) {
+        if let Some((store, ttl)) = &mut self.state().cache {
+            store.put(key, Arc::clone(weather), *ttl);
+        }
+    }
+
+    fn state(&self) -> MutexGuard<'_, State> {
+        // The state stays consistent even if a thread panicked while holding the lock
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// Outcome of one attempt at a call
+enum Response {
+    Weather(Arc<Weather>),
+
+    /// An unsuccessful HTTP status
+    Failed {
+        status: u16,
+        body: String,
+        retry_after: Option<Duration>,
+    },
+
+    /// No response, or one that couldn't be read
+    Unsent(Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cache::MemoryCache;
+    use futures_util::StreamExt;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+    };
+
+    /// Serve `responses` in order, one per connection, sending each request's head to the
+    /// returned channel
+    fn serve(responses: Vec<(u16, &'static str, String)>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (requests, received) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, headers, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut head = String::new();
+                let mut reader = BufReader::new(&stream);
+                while reader.read_line(&mut head).unwrap() > 2 && !head.ends_with("\r\n\r\n") {}
+                requests.send(head).unwrap();
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} X\r\n{headers}Content-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+        });
+        (url, received)
+    }
+
+    fn ok(headers: &'static str) -> (u16, &'static str, String) {
+        (200, headers, crate::fixtures::FULL_JSON.to_string())
+    }
+
+    #[tokio::test]
+    async fn revalidate_and_cache() {
+        let (url, requests) = serve(vec![ok("ETag: \"5f2c\"\r\n"), (304, "", String::new())]);
+        let client = Client::builder(ApiKey::new("abc123"))
+            .base_url(url)
+            .units(Units::Metric)
+            .build()
+            .unwrap();
+
+        let first = client.one_call(49.8951, -97.1384).await.unwrap();
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with(
+            "GET /data/3.0/onecall?lat=49.8951&lon=-97.1384&units=metric&lang=en&appid=abc123 "
+        ));
+
+        let second = client.one_call(49.8951, -97.1384).await.unwrap();
+        assert!(requests.recv().unwrap().contains("if-none-match: \"5f2c\""));
+        assert!(Arc::ptr_eq(&first, &second));
+        let quota = client.quota();
+        assert_eq!(2, quota.calls_today(Endpoint::OneCall, Timestamp::now()));
+
+        // Nearby points are answered from the cache without a request
+        let (url, requests) = serve(vec![ok("")]);
+        let client = Client::builder(ApiKey::new("abc123"))
+            .base_url(url)
+            .cache(MemoryCache::new(), Duration::from_secs(600))
+            .build()
+            .unwrap();
+        client.one_call(49.8951, -97.1384).await.unwrap();
+        client.one_call(49.8972, -97.1381).await.unwrap();
+        assert_eq!(1, requests.try_iter().count());
+    }
+
+    #[tokio::test]
+    async fn retry_and_limit() {
+        let (url, _requests) = serve(vec![
+            (503, "", String::new()),
+            (429, "Retry-After: 0\r\n", String::new()),
+            ok(""),
+            (
+                401,
+                "",
+                r#"{ "cod": 401, "message": "Invalid API key." }"#.to_string(),
+            ),
+        ]);
+        let policy = RetryPolicy {
+            base_delay: Duration::ZERO,
+            ..RetryPolicy::default()
+        };
+        let plan = Plan::default().with(Endpoint::OneCall, crate::rate_limit::Limit::per_day(4));
+        let client = Client::builder(ApiKey::new("abc123"))
+            .base_url(url)
+            .retry(policy)
+            .plan(plan)
+            .build()
+            .unwrap();
+
+        assert!(client.one_call(0.0, 0.0).await.is_ok());
+        assert!(matches!(
+            client.one_call(0.0, 0.0).await,
+            Err(Error::InvalidApiKey)
+        ));
+        assert!(matches!(
+            client.one_call(0.0, 0.0).await,
+            Err(Error::RateLimitExhausted { .. })
+        ));
+    }
+
+    #[tokio::test]
+    async fn watch_skips_unchanged() {
+        let changed = crate::fixtures::FULL_JSON.replacen("\"temp\": 27.9", "\"temp\": 30.1", 1);
+        let (url, requests) = serve(vec![ok(""), ok(""), (200, "", changed)]);
+        let client = Client::builder(ApiKey::new("abc123"))
+            .base_url(url)
+            .build()
+            .unwrap();
+
+        let responses: Vec<_> = client
+            .watch(0.0, 0.0, Duration::from_millis(1))
+            .take(2)
+            .collect()
+            .await;
+        assert_eq!(3, requests.try_iter().count());
+        let temps: Vec<_> = responses
+            .iter()
+            .map(|x| x.as_ref().unwrap().hourly.as_ref().unwrap()[0].temp)
+            .collect();
+        assert_eq!(vec![27.9, 30.1], temps);
+    }
+}
@@ -0,0 +1,135 @@
+//! Side by side comparison of the weather at two locations.
+
+use crate::Weather;
+use jiff::{Span, Zoned};
+
+/// Which of the two compared locations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    A,
+    B,
+
+    /// Both locations at the same time
+    Both,
+}
+
+/// Differences between location `a` and location `b`, as returned by [`compare`]. Temperature
+/// differences are `a` minus `b`, in the units of the requests.
+#[derive(Debug)]
+pub struct LocationComparison {
+    /// Difference in current temperature, if both responses include it
+    pub current_temperature: Option<f64>,
+
+    /// Daily differences, for as many days as both responses include
+    pub daily: Vec<DayComparison>,
+
+    /// Where and when precipitation is first expected in the hourly forecasts, if at all
+    pub first_precipitation: Option<(Side, Zoned)>,
+}
+
+/// Differences for a single day
+#[derive(Debug)]
+pub struct DayComparison {
+    /// Time of the day at location `a`
+    pub dt: Zoned,
+
+    /// Difference in minimum temperature
+    pub min_temperature: f64,
+
+    /// Difference in maximum temperature
+    pub max_temperature: f64,
+
+    /// How much later the sun rises at `b` than at `a`. Negative if it rises earlier, and `None`
+    /// if it doesn't rise at either during polar day or night.
+    pub sunrise_offset: Option<Span>,
+}
+
+/// Compare responses for two locations, requested in the same units. Days are paired in order,
+/// so both responses should be for the same days.
+pub fn compare(a: &Weather, b: &Weather) -> LocationComparison {
+    let current_temperature = a
+        .current
+        .as_ref()
+        .zip(b.current.as_ref())
+        .map(|(a, b)| a.temp - b.temp);
+
+    let daily = a
+        .daily
+        .iter()
+        .flatten()
+        .zip(b.daily.iter().flatten())
+        .map(|(a, b)| DayComparison {
+            dt: a.dt.clone(),
+            min_temperature: a.temp.min - b.temp.min,
+            max_temperature: a.temp.max - b.temp.max,
+            sunrise_offset: (b.sunrise.as_ref())
+                .zip(a.sunrise.as_ref())
+                .map(|(b, a)| b - a),
+        })
+        .collect();
+
+    let first_precipitation = match (first_precipitation(a), first_precipitation(b)) {
+        (Some(a), Some(b)) if a == b => Some((Side::Both, a.clone())),
+        (Some(a), Some(b)) if b < a => Some((Side::B, b.clone())),
+        (Some(a), _) => Some((Side::A, a.clone())),
+        (None, Some(b)) => Some((Side::B, b.clone())),
+        (None, None) => None,
+    };
+
+    LocationComparison {
+        current_temperature,
+        daily,
+        first_precipitation,
+    }
+}
+
+fn first_precipitation(weather: &Weather) -> Option<&Zoned> {
+    weather
+        .hourly
+        .iter()
+        .flatten()
+        .find(|x| x.total_precipitation().millimetres() > 0.0)
+        .map(|x| &x.dt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compare_locations() {
+        let a = crate::fixtures::full();
+        let mut b = crate::fixtures::full();
+        let first = first_precipitation(&a).unwrap().clone();
+
+        let comparison = compare(&a, &b);
+        assert_eq!(Some(0.0), comparison.current_temperature);
+        assert_eq!(3, comparison.daily.len());
+        assert_eq!(
+            Some((Side::Both, first.clone())),
+            comparison.first_precipitation
+        );
+
+        b.current.as_mut().unwrap().temp -= 2.5;
+        let day = &mut b.daily.as_mut().unwrap()[0];
+        day.temp.max += 1.0;
+        day.sunrise = day.sunrise.as_ref().map(|x| x + Span::new().minutes(4));
+        for hour in b.hourly.as_mut().unwrap() {
+            hour.rain = None;
+            hour.snow = None;
+        }
+
+        let comparison = compare(&a, &b);
+        assert_eq!(Some(2.5), comparison.current_temperature);
+        assert_eq!(-1.0, comparison.daily[0].max_temperature);
+        assert_eq!(
+            4,
+            comparison.daily[0]
+                .sunrise_offset
+                .unwrap()
+                .total(jiff::Unit::Minute)
+                .unwrap() as i64
+        );
+        assert_eq!(Some((Side::A, first)), comparison.first_precipitation);
+    }
+}
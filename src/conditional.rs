@@ -0,0 +1,105 @@
+//! Conditional requests with `ETag` and `Last-Modified` validators.
+//!
+//! A [`ConditionalCache`] remembers the validators and parsed response of each request URL. Send
+//! [`ConditionalCache::request_headers`] with the next request to the same URL, and on a
+//! `304 Not Modified` response use [`ConditionalCache::not_modified`] instead of parsing a body.
+
+use crate::Weather;
+use std::{collections::HashMap, sync::Arc};
+
+/// Validators of a response, from its `ETag` and `Last-Modified` headers
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Validators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl Validators {
+    /// Pick the validators out of response headers. Header names are case insensitive.
+    pub fn from_headers<'a>(headers: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut validators = Self::default();
+        for (name, value) in headers {
+            if name.eq_ignore_ascii_case("etag") {
+                validators.etag = Some(value.to_string());
+            } else if name.eq_ignore_ascii_case("last-modified") {
+                validators.last_modified = Some(value.to_string());
+            }
+        }
+        validators
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+
+    /// `If-None-Match` and `If-Modified-Since` headers for the next request
+    pub fn request_headers(&self) -> Vec<(&'static str, &str)> {
+        let etag = self.etag.as_deref().map(|x| ("If-None-Match", x));
+        let modified = (self.last_modified.as_deref()).map(|x| ("If-Modified-Since", x));
+        etag.into_iter().chain(modified).collect()
+    }
+}
+
+/// Last response with validators for each request URL
+#[derive(Debug, Default)]
+pub struct ConditionalCache {
+    entries: HashMap<String, (Validators, Arc<Weather>)>,
+}
+
+impl ConditionalCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Conditional headers to send with a request to `url`. Empty if nothing is stored for it.
+    pub fn request_headers(&self, url: &str) -> Vec<(&'static str, &str)> {
+        self.entries
+            .get(url)
+            .map(|(validators, _)| validators.request_headers())
+            .unwrap_or_default()
+    }
+
+    /// Remember a `200 OK` response to `url`. Responses without validators aren't stored.
+    pub fn store(&mut self, url: &str, validators: Validators, weather: Arc<Weather>) {
+        if validators.is_empty() {
+            self.entries.remove(url);
+        } else {
+            self.entries.insert(url.to_string(), (validators, weather));
+        }
+    }
+
+    /// The stored response to `url`, for when the server answers `304 Not Modified`
+    pub fn not_modified(&self, url: &str) -> Option<Arc<Weather>> {
+        self.entries
+            .get(url)
+            .map(|(_, weather)| Arc::clone(weather))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn revalidate() {
+        let weather = Arc::new(crate::fixtures::full());
+        let url = "https://api.openweathermap.org/data/3.0/onecall?lat=49.9&lon=-97.1";
+        let validators = Validators::from_headers([
+            ("Content-Type", "application/json"),
+            ("ETag", "\"5f2c\""),
+            ("last-modified", "Thu, 11 Jul 2024 12:00:00 GMT"),
+        ]);
+
+        let mut cache = ConditionalCache::new();
+        assert!(cache.request_headers(url).is_empty());
+        cache.store(url, validators, weather);
+        assert_eq!(
+            vec![
+                ("If-None-Match", "\"5f2c\""),
+                ("If-Modified-Since", "Thu, 11 Jul 2024 12:00:00 GMT")
+            ],
+            cache.request_headers(url)
+        );
+        assert!(cache.not_modified(url).is_some());
+    }
+}
@@ -0,0 +1,100 @@
+//! Configuration files for small tools built on this crate.
+//!
+//! A config file is TOML, with every key optional:
+//!
+//! ```toml
+//! api_key = "abc123"
+//! units = "metric"
+//! lat = 49.8951
+//! lon = -97.1384
+//! ```
+
+use crate::{ApiKey, Units};
+use serde::Deserialize;
+use std::{fmt, io, path::Path};
+
+/// API key, units, and location defaults
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    pub api_key: Option<ApiKey>,
+
+    /// Value for the `units` request parameter: `"standard"`, `"metric"`, or `"imperial"`
+    pub units: Units,
+
+    /// Default latitude
+    pub lat: Option<f64>,
+
+    /// Default longitude
+    pub lon: Option<f64>,
+}
+
+/// A config file that couldn't be read or parsed
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(io::Error),
+    Parse(toml::de::Error),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "couldn't read config file: {e}"),
+            Self::Parse(e) => write!(f, "invalid config file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Parse(e) => Some(e),
+        }
+    }
+}
+
+impl Config {
+    /// Parse a config file's contents
+    pub fn from_toml(s: &str) -> Result<Self, ConfigError> {
+        toml::from_str(s).map_err(ConfigError::Parse)
+    }
+
+    /// Read and parse the config file at `path`. A key from [`ApiKey::from_env`] takes precedence
+    /// over the one in the file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ConfigError> {
+        let s = std::fs::read_to_string(path).map_err(ConfigError::Io)?;
+        let mut config = Self::from_toml(&s)?;
+        if let Some(key) = ApiKey::from_env() {
+            config.api_key = Some(key);
+        }
+        Ok(config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::from_toml(
+            r#"
+            api_key = "abc123"
+            units = "imperial"
+            lat = 49.8951
+            "#,
+        )
+        .unwrap();
+        assert_eq!("abc123", config.api_key.unwrap().expose());
+        assert_eq!(Units::Imperial, config.units);
+        assert_eq!(Some(49.8951), config.lat);
+        assert_eq!(None, config.lon);
+
+        assert_eq!(Units::Standard, Config::from_toml("").unwrap().units);
+        assert!(matches!(
+            Config::from_toml(r#"units = "kelvin""#),
+            Err(ConfigError::Parse(_))
+        ));
+    }
+}
@@ -0,0 +1,246 @@
+//! Models for the current weather data API: <https://openweathermap.org/current>
+
+use crate::{ts_seconds, Precipitation, WeatherElement};
+use jiff::Zoned;
+use serde::{Deserialize, Serialize};
+
+/// Current weather data API response (`/data/2.5/weather`)
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct CurrentWeather {
+    /// Coordinates of the location
+    pub coord: Coord,
+
+    pub weather: Vec<WeatherElement>,
+
+    /// Internal parameter
+    pub base: Option<String>,
+
+    pub main: MainParameters,
+
+    /// Visibility, metres. Usually at most 10km, though larger values are sometimes reported
+    #[serde(default, deserialize_with = "crate::integer::option::deserialize")]
+    pub visibility: Option<u32>,
+
+    pub wind: Wind,
+
+    pub clouds: Clouds,
+
+    /// (where available) Rain volume, mm
+    #[serde(
+        default,
+        deserialize_with = "crate::lenient::precipitation::deserialize"
+    )]
+    pub rain: Option<Precipitation>,
+
+    /// (where available) Snow volume, mm
+    #[serde(
+        default,
+        deserialize_with = "crate::lenient::precipitation::deserialize"
+    )]
+    pub snow: Option<Precipitation>,
+
+    /// Time of data calculation, unix, UTC
+    #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub dt: Zoned,
+
+    pub sys: Sys,
+
+    /// Shift in seconds from UTC
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub timezone: i32,
+
+    /// City ID
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub id: i64,
+
+    /// City name
+    pub name: String,
+
+    /// Internal parameter
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub cod: i32,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct Coord {
+    /// Longitude of the location
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub lon: f64,
+
+    /// Latitude of the location
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub lat: f64,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct MainParameters {
+    /// Temperature. Unit Default: Kelvin, Metric: Celsius, Imperial: Fahrenheit.
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub temp: f64,
+
+    /// Temperature. This temperature parameter accounts for the human perception of weather.
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub feels_like: f64,
+
+    /// Minimum temperature at the moment. This is minimal currently observed temperature (within large megalopolises and urban areas).
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub temp_min: f64,
+
+    /// Maximum temperature at the moment. This is maximal currently observed temperature (within large megalopolises and urban areas).
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub temp_max: f64,
+
+    /// Atmospheric pressure on the sea level, hPa
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub pressure: u16,
+
+    /// Humidity, %
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub humidity: u8,
+
+    /// Atmospheric pressure on the sea level, hPa
+    #[serde(default, deserialize_with = "crate::integer::option::deserialize")]
+    pub sea_level: Option<u16>,
+
+    /// Atmospheric pressure on the ground level, hPa
+    #[serde(default, deserialize_with = "crate::integer::option::deserialize")]
+    pub grnd_level: Option<u16>,
+
+    /// Internal parameter, only present in forecasts
+    #[serde(default, deserialize_with = "crate::lenient::option::deserialize")]
+    pub temp_kf: Option<f64>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct Wind {
+    /// Wind speed. Unit Default: meter/sec, Metric: meter/sec, Imperial: miles/hour.
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub speed: f64,
+
+    /// Wind direction, degrees (meteorological)
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub deg: u16,
+
+    /// (where available) Wind gust. Unit Default: meter/sec, Metric: meter/sec, Imperial: miles/hour.
+    #[serde(default, deserialize_with = "crate::lenient::option::deserialize")]
+    pub gust: Option<f64>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct Clouds {
+    /// Cloudiness, %
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub all: u8,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct Sys {
+    /// Internal parameter
+    #[serde(
+        rename = "type",
+        default,
+        deserialize_with = "crate::integer::option::deserialize"
+    )]
+    pub kind: Option<i32>,
+
+    /// Internal parameter
+    #[serde(default, deserialize_with = "crate::integer::option::deserialize")]
+    pub id: Option<i64>,
+
+    /// Country code (GB, JP etc.)
+    pub country: Option<String>,
+
+    /// Sunrise time, unix, UTC
+    #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub sunrise: Zoned,
+
+    /// Sunset time, unix, UTC
+    #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub sunset: Zoned,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_current_weather() {
+        let weather: CurrentWeather =
+            serde_json::from_str(include_str!("../fixtures/current_weather.json")).unwrap();
+
+        assert_eq!("Zocca", weather.name);
+        assert_eq!(Some(3.16), weather.rain.unwrap().one_hour);
+        assert_eq!(Some(933), weather.main.grnd_level);
+        assert_eq!(Some("IT"), weather.sys.country.as_deref());
+    }
+
+    #[test]
+    fn wire_format() {
+        let weather: CurrentWeather =
+            serde_json::from_str(include_str!("../fixtures/current_weather.json")).unwrap();
+        insta::assert_json_snapshot!(weather);
+    }
+}
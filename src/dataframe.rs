@@ -0,0 +1,172 @@
+//! Conversion of hourly and daily forecasts to [Polars](https://docs.rs/polars) data frames.
+//!
+//! The frames have the same columns, in the same order, as the CSV [`export`](crate::export).
+//! Times are `Datetime(Milliseconds, "UTC")` columns, and values missing from the response are
+//! nulls. Use [`Weather::hourly_df`](crate::Weather::hourly_df) and
+//! [`Weather::daily_df`](crate::Weather::daily_df).
+
+use crate::{
+    export::{DAILY_COLUMNS, HOURLY_COLUMNS},
+    Daily, Hourly, WeatherElement,
+};
+use jiff::Zoned;
+use polars::prelude::{
+    Column, DataFrame, DataType, NamedFrom, PolarsResult, Series, TimeUnit, TimeZone,
+};
+
+pub(crate) fn hourly(hourly: &[Hourly]) -> PolarsResult<DataFrame> {
+    let mut columns = Columns::new(HOURLY_COLUMNS);
+    columns.times(hourly.iter().map(|x| Some(&x.dt)))?;
+    columns.push(hourly.iter().map(|x| x.temp).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.feels_like).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.pressure).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.humidity).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.dew_point).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.uvi).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.clouds).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.visibility).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.wind_speed).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.wind_gust).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.wind_deg).collect::<Vec<_>>());
+    columns.push(hourly.iter().map(|x| x.pop).collect::<Vec<_>>());
+    columns.push(
+        hourly
+            .iter()
+            .map(|x| x.rain.as_ref().map(|x| x.volume().0))
+            .collect::<Vec<_>>(),
+    );
+    columns.push(
+        hourly
+            .iter()
+            .map(|x| x.snow.as_ref().map(|x| x.volume().0))
+            .collect::<Vec<_>>(),
+    );
+    columns.conditions(hourly.iter().map(|x| &x.weather[..]));
+    columns.frame()
+}
+
+pub(crate) fn daily(daily: &[Daily]) -> PolarsResult<DataFrame> {
+    let mut columns = Columns::new(DAILY_COLUMNS);
+    columns.times(daily.iter().map(|x| Some(&x.dt)))?;
+    columns.times(daily.iter().map(|x| x.sunrise.as_ref()))?;
+    columns.times(daily.iter().map(|x| x.sunset.as_ref()))?;
+    columns.times(daily.iter().map(|x| Some(&x.moonrise)))?;
+    columns.times(daily.iter().map(|x| Some(&x.moonset)))?;
+    columns.push(daily.iter().map(|x| x.moon_phase).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.temp.min).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.temp.max).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.temp.morn).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.temp.day).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.temp.eve).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.temp.night).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.feels_like.morn).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.feels_like.day).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.feels_like.eve).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.feels_like.night).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.pressure).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.humidity).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.dew_point).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.wind_speed).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.wind_gust).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.wind_deg).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.clouds).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.uvi).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.pop).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.rain).collect::<Vec<_>>());
+    columns.push(daily.iter().map(|x| x.snow).collect::<Vec<_>>());
+    columns.conditions(daily.iter().map(|x| &x.weather[..]));
+    columns.push(
+        daily
+            .iter()
+            .map(|x| x.summary.as_deref())
+            .collect::<Vec<_>>(),
+    );
+    columns.frame()
+}
+
+/// Columns of a data frame, named in order from a list of column names
+struct Columns {
+    names: std::slice::Iter<'static, &'static str>,
+    columns: Vec<Column>,
+}
+
+impl Columns {
+    fn new(names: &'static [&'static str]) -> Self {
+        Self {
+            names: names.iter(),
+            columns: Vec::new(),
+        }
+    }
+
+    fn push<T, P: ?Sized>(&mut self, values: T)
+    where
+        Series: NamedFrom<T, P>,
+    {
+        let name = self.names.next().expect("a name for each column");
+        self.columns
+            .push(Series::new((*name).into(), values).into());
+    }
+
+    fn times<'a>(&mut self, values: impl Iterator<Item = Option<&'a Zoned>>) -> PolarsResult<()> {
+        let millis: Vec<Option<i64>> = values
+            .map(|x| Some(x?.timestamp().as_millisecond()))
+            .collect();
+        self.push(millis);
+        let column = self.columns.last_mut().expect("just pushed");
+        *column = column.cast(&DataType::Datetime(
+            TimeUnit::Milliseconds,
+            Some(TimeZone::UTC),
+        ))?;
+        Ok(())
+    }
+
+    /// Id, group, and description of each entry's primary condition
+    fn conditions<'a>(&mut self, weather: impl Iterator<Item = &'a [WeatherElement]> + Clone) {
+        let first = weather.map(|x| x.first());
+        self.push(first.clone().map(|x| Some(x?.id)).collect::<Vec<_>>());
+        self.push(
+            first
+                .clone()
+                .map(|x| Some(format!("{:?}", x?.main)))
+                .collect::<Vec<_>>(),
+        );
+        self.push(
+            first
+                .map(|x| Some(x?.description.as_str()))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    fn frame(self) -> PolarsResult<DataFrame> {
+        DataFrame::new(self.columns)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn data_frames() {
+        let weather = crate::fixtures::full();
+        let df = weather.hourly_df().unwrap();
+        assert_eq!(weather.hourly.as_ref().unwrap().len(), df.height());
+        assert_eq!(HOURLY_COLUMNS, df.get_column_names_str().as_slice());
+        assert_eq!(
+            &DataType::Datetime(TimeUnit::Milliseconds, Some(TimeZone::UTC)),
+            df.column("dt").unwrap().dtype()
+        );
+        assert_eq!(&DataType::UInt8, df.column("humidity").unwrap().dtype());
+        let dry = weather
+            .hourly
+            .iter()
+            .flatten()
+            .filter(|x| x.rain.is_none())
+            .count();
+        assert_eq!(dry, df.column("rain").unwrap().null_count());
+
+        let df = weather.daily_df().unwrap();
+        assert_eq!(DAILY_COLUMNS, df.get_column_names_str().as_slice());
+        assert_eq!(weather.daily.as_ref().unwrap().len(), df.height());
+    }
+}
@@ -0,0 +1,89 @@
+//! Shareable bundles for reporting parse failures.
+//!
+//! A [`DebugBundle`] captures everything needed to reproduce a parse failure, with the API key
+//! removed from the request, and can be serialized into a single file to attach to a bug report.
+
+use serde::Serialize;
+use std::fmt;
+
+/// Request, response, and parse outcome of a single API call
+#[derive(Debug, Serialize)]
+pub struct DebugBundle {
+    /// Version of this crate
+    pub crate_version: &'static str,
+
+    /// Request URL, with the API key redacted
+    pub request: String,
+
+    /// Raw response body
+    pub response: String,
+
+    /// Error message if the response failed to parse
+    pub parse_error: Option<String>,
+}
+
+impl DebugBundle {
+    /// Capture a request and its response, parsing the response with `parse`
+    ///
+    /// ```
+    /// use owm_rs::{debug::DebugBundle, Weather};
+    ///
+    /// let url = "https://api.openweathermap.org/data/3.0/onecall?lat=49.9&lon=-97.1&appid=abc123";
+    /// let bundle = DebugBundle::new(url, "{}", |s| serde_json::from_str::<Weather>(s));
+    /// assert_eq!(
+    ///     "https://api.openweathermap.org/data/3.0/onecall?lat=49.9&lon=-97.1&appid=REDACTED",
+    ///     bundle.request
+    /// );
+    /// ```
+    pub fn new<T, E, F>(request: &str, response: &str, parse: F) -> Self
+    where
+        F: FnOnce(&str) -> Result<T, E>,
+        E: fmt::Display,
+    {
+        Self {
+            crate_version: env!("CARGO_PKG_VERSION"),
+            request: redact_api_key(request),
+            response: response.to_string(),
+            parse_error: parse(response).err().map(|e| e.to_string()),
+        }
+    }
+}
+
+/// Replace the value of the `appid` query parameter in `url` with `REDACTED`
+pub fn redact_api_key(url: &str) -> String {
+    let Some((base, query)) = url.split_once('?') else {
+        return url.to_string();
+    };
+
+    let query = query
+        .split('&')
+        .map(|pair| match pair.split_once('=') {
+            Some(("appid", _)) => "appid=REDACTED",
+            _ => pair,
+        })
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{base}?{query}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Weather;
+
+    #[test]
+    fn capture_parse_error() {
+        let bundle = DebugBundle::new(
+            "https://api.openweathermap.org/data/3.0/onecall?appid=abc123&lat=49.9&lon=-97.1",
+            r#"{ "current": { "dt": "soon" } }"#,
+            |s| serde_json::from_str::<Weather>(s),
+        );
+
+        assert_eq!(
+            "https://api.openweathermap.org/data/3.0/onecall?appid=REDACTED&lat=49.9&lon=-97.1",
+            bundle.request
+        );
+        assert!(bundle.parse_error.unwrap().contains("line 1 column"));
+        assert_eq!("no query", redact_api_key("no query"));
+    }
+}
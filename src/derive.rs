@@ -0,0 +1,181 @@
+//! Quantities derived from the reported values using standard formulas.
+//!
+//! Unless stated otherwise, temperatures are in Celsius and humidity is in %.
+
+use jiff::Zoned;
+
+/// Magnus formula coefficient (Alduchov & Eskridge, 1996)
+const MAGNUS_B: f64 = 17.625;
+
+/// Magnus formula coefficient (Alduchov & Eskridge, 1996), °C
+const MAGNUS_C: f64 = 243.04;
+
+/// Dew point computed with the Magnus formula, °C
+///
+/// Accurate to within about 0.1 °C between -40 °C and 50 °C. Returns `NaN` for a humidity of 0.
+pub fn dew_point(temp: f64, humidity: f64) -> f64 {
+    let gamma = (humidity / 100.0).ln() + MAGNUS_B * temp / (MAGNUS_C + temp);
+    MAGNUS_C * gamma / (MAGNUS_B - gamma)
+}
+
+/// NWS heat index, °C
+///
+/// Uses the Rothfusz regression with the NWS adjustments, falling back to Steadman's simpler
+/// formula when the result would be below 80 °F (26.7 °C), where the regression doesn't apply.
+pub fn heat_index(temp: f64, humidity: f64) -> f64 {
+    let t = temp * 9.0 / 5.0 + 32.0;
+    let rh = humidity;
+
+    let simple = 0.5 * (t + 61.0 + (t - 68.0) * 1.2 + rh * 0.094);
+    let hi = if (simple + t) / 2.0 < 80.0 {
+        simple
+    } else {
+        let mut hi = -42.379 + 2.049_015_23 * t + 10.143_331_27 * rh
+            - 0.224_755_41 * t * rh
+            - 0.006_837_83 * t * t
+            - 0.054_817_17 * rh * rh
+            + 0.001_228_74 * t * t * rh
+            + 0.000_852_82 * t * rh * rh
+            - 0.000_001_99 * t * t * rh * rh;
+        if rh < 13.0 && (80.0..=112.0).contains(&t) {
+            hi -= (13.0 - rh) / 4.0 * ((17.0 - (t - 95.0).abs()) / 17.0).sqrt();
+        } else if rh > 85.0 && (80.0..=87.0).contains(&t) {
+            hi += (rh - 85.0) / 10.0 * ((87.0 - t) / 5.0);
+        }
+        hi
+    };
+
+    (hi - 32.0) * 5.0 / 9.0
+}
+
+/// North American wind chill index, °C
+///
+/// `wind_speed` is in km/h. Returns `None` outside of where the index is defined: above 10 °C,
+/// or with winds of 4.8 km/h or less.
+pub fn wind_chill(temp: f64, wind_speed: f64) -> Option<f64> {
+    if temp > 10.0 || wind_speed <= 4.8 {
+        return None;
+    }
+
+    let v = wind_speed.powf(0.16);
+    Some(13.12 + 0.6215 * temp - 11.37 * v + 0.3965 * temp * v)
+}
+
+/// Canadian humidex, °C
+pub fn humidex(temp: f64, dew_point: f64) -> f64 {
+    let vapour_pressure = 6.11 * (5417.753 * (1.0 / 273.16 - 1.0 / (273.15 + dew_point))).exp();
+    temp + 0.5555 * (vapour_pressure - 10.0)
+}
+
+/// Saturation vapour pressure at `temp`, kPa
+fn saturation_vapour_pressure(temp: f64) -> f64 {
+    0.6108 * (17.27 * temp / (temp + 237.3)).exp()
+}
+
+/// Daily FAO-56 Penman-Monteith reference evapotranspiration, mm/day
+///
+/// Solar radiation isn't reported by OWM, so it's estimated from the daily temperature range
+/// (Hargreaves' radiation formula), as recommended by FAO-56 when radiation data is missing.
+///
+/// - `temp_min`, `temp_max`, `dew_point`: °C
+/// - `wind_speed`: metre/sec, measured at 10 m as reported by OWM
+/// - `pressure`: atmospheric pressure at the site (not at sea level), hPa
+/// - `latitude`: degrees, positive north
+/// - `day_of_year`: 1 to 366
+pub fn reference_evapotranspiration(
+    temp_min: f64,
+    temp_max: f64,
+    dew_point: f64,
+    wind_speed: f64,
+    pressure: f64,
+    latitude: f64,
+    day_of_year: i16,
+) -> f64 {
+    use std::f64::consts::PI;
+
+    let temp = (temp_min + temp_max) / 2.0;
+    let slope = 4098.0 * saturation_vapour_pressure(temp) / (temp + 237.3).powi(2);
+    let psychrometric = 0.000_665 * pressure / 10.0;
+
+    let saturation =
+        (saturation_vapour_pressure(temp_max) + saturation_vapour_pressure(temp_min)) / 2.0;
+    let actual = saturation_vapour_pressure(dew_point);
+
+    // Extraterrestrial radiation, MJ/m²/day
+    let day = 2.0 * PI * f64::from(day_of_year) / 365.0;
+    let distance = 1.0 + 0.033 * day.cos();
+    let declination = 0.409 * (day - 1.39).sin();
+    let latitude = latitude.to_radians();
+    let sunset_angle = (-latitude.tan() * declination.tan())
+        .clamp(-1.0, 1.0)
+        .acos();
+    let extraterrestrial = 24.0 * 60.0 / PI
+        * 0.0820
+        * distance
+        * (sunset_angle * latitude.sin() * declination.sin()
+            + latitude.cos() * declination.cos() * sunset_angle.sin());
+
+    let solar = 0.16 * (temp_max - temp_min).max(0.0).sqrt() * extraterrestrial;
+    let clear_sky = 0.75 * extraterrestrial;
+    let cloudiness = if clear_sky > 0.0 {
+        1.35 * (solar / clear_sky).min(1.0) - 0.35
+    } else {
+        0.0
+    };
+    let longwave = 4.903e-9 * ((temp_max + 273.16).powi(4) + (temp_min + 273.16).powi(4)) / 2.0
+        * (0.34 - 0.14 * actual.sqrt())
+        * cloudiness;
+    let net_radiation = 0.77 * solar - longwave;
+
+    // Wind speed at 2 m
+    let wind_speed = wind_speed * 4.87 / (67.8 * 10.0 - 5.42_f64).ln();
+
+    let et = (0.408 * slope * net_radiation
+        + psychrometric * 900.0 / (temp + 273.0) * wind_speed * (saturation - actual))
+        / (slope + psychrometric * (1.0 + 0.34 * wind_speed));
+    et.max(0.0)
+}
+
+/// An entry whose reported dew point disagrees with the one computed from its temperature and
+/// humidity. Temperatures are in the units of the request.
+#[derive(Debug)]
+pub struct DewPointMismatch {
+    /// Time of the entry
+    pub dt: Zoned,
+
+    /// Dew point reported by OWM
+    pub reported: f64,
+
+    /// Dew point computed with [`dew_point`]
+    pub computed: f64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn magnus_dew_point() {
+        assert!((dew_point(20.0, 50.0) - 9.26).abs() < 0.01);
+        assert!((dew_point(-10.0, 100.0) + 10.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn comfort_indices() {
+        // NWS heat index table: 90 °F at 60% humidity is 100 °F (99.7 °F before rounding)
+        assert!((heat_index(32.222, 60.0) - 37.6).abs() < 0.1);
+        // Environment Canada wind chill table: -20 °C at 30 km/h is -33
+        assert_eq!(-33.0, wind_chill(-20.0, 30.0).unwrap().round());
+        assert_eq!(None, wind_chill(15.0, 30.0));
+        // Environment Canada humidex table: 30 °C with a 20 °C dew point is 38
+        assert_eq!(38.0, humidex(30.0, 20.0).round());
+    }
+
+    #[test]
+    fn penman_monteith() {
+        // FAO-56 example 18 gives 3.9 mm/day with measured radiation; estimating radiation from
+        // the temperature range lands close to it
+        let et = reference_evapotranspiration(12.3, 21.5, 12.1, 2.078 / 0.748, 1001.0, 50.8, 187);
+        assert!((et - 3.9).abs() < 0.5, "{et}");
+    }
+}
@@ -0,0 +1,130 @@
+//! Meaningful changes between two responses for the same location.
+
+use crate::{alerts::same_alert, Alert, Main, Weather};
+use jiff::Zoned;
+
+/// Changes from an older response to a newer one, as returned by [`Weather::diff`]
+#[derive(Debug)]
+pub struct Changeset<'a> {
+    /// Alerts in the newer response only
+    pub new_alerts: Vec<&'a Alert>,
+
+    /// Alerts in the older response only
+    pub removed_alerts: Vec<&'a Alert>,
+
+    /// Hours in both responses whose forecasted temperature changed
+    pub temperatures: Vec<TemperatureChange>,
+
+    /// Hours in both responses whose primary condition group changed
+    pub conditions: Vec<ConditionChange>,
+}
+
+/// A changed hourly temperature, in the units of the requests
+#[derive(Debug, PartialEq)]
+pub struct TemperatureChange {
+    pub dt: Zoned,
+    pub before: f64,
+    pub after: f64,
+}
+
+impl TemperatureChange {
+    /// Difference from before to after
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// A changed hourly condition, such as clear to rain
+#[derive(Debug, PartialEq)]
+pub struct ConditionChange {
+    pub dt: Zoned,
+    pub before: Main,
+    pub after: Main,
+}
+
+impl Changeset<'_> {
+    /// Whether nothing changed
+    pub fn is_empty(&self) -> bool {
+        self.new_alerts.is_empty()
+            && self.removed_alerts.is_empty()
+            && self.temperatures.is_empty()
+            && self.conditions.is_empty()
+    }
+}
+
+pub(crate) fn diff<'a>(older: &'a Weather, newer: &'a Weather) -> Changeset<'a> {
+    let only_in = |a: &'a Weather, b: &'a Weather| {
+        a.alerts
+            .iter()
+            .flatten()
+            .filter(|x| !b.alerts.iter().flatten().any(|y| same_alert(x, y)))
+            .collect()
+    };
+
+    let mut temperatures = Vec::new();
+    let mut conditions = Vec::new();
+    for after in newer.hourly.iter().flatten() {
+        let Some(before) = older.hourly.iter().flatten().find(|x| x.dt == after.dt) else {
+            continue;
+        };
+
+        if before.temp != after.temp {
+            temperatures.push(TemperatureChange {
+                dt: after.dt.clone(),
+                before: before.temp,
+                after: after.temp,
+            });
+        }
+
+        let main = |x: &crate::Hourly| x.weather.first().map(|x| x.main);
+        if let (Some(a), Some(b)) = (main(before), main(after)) {
+            if a != b {
+                conditions.push(ConditionChange {
+                    dt: after.dt.clone(),
+                    before: a,
+                    after: b,
+                });
+            }
+        }
+    }
+
+    Changeset {
+        new_alerts: only_in(newer, older),
+        removed_alerts: only_in(older, newer),
+        temperatures,
+        conditions,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_responses() {
+        let json = crate::fixtures::FULL_JSON;
+        let older: Weather = serde_json::from_str(json).unwrap();
+        let mut newer: Weather = serde_json::from_str(json).unwrap();
+        assert!(older.diff(&newer).is_empty());
+
+        let hourly = newer.hourly.as_mut().unwrap();
+        hourly[1].temp += 1.5;
+        hourly[2].weather[0].main = Main::Clear;
+        newer.alerts = None;
+
+        let changes = older.diff(&newer);
+        assert!(changes.new_alerts.is_empty());
+        assert_eq!(1, changes.removed_alerts.len());
+        assert_eq!(1, changes.temperatures.len());
+        assert_eq!(1.5, changes.temperatures[0].delta());
+        let before = older.hourly.as_ref().unwrap()[2].weather[0].main;
+        assert_eq!(
+            vec![ConditionChange {
+                dt: older.hourly.as_ref().unwrap()[2].dt.clone(),
+                before,
+                after: Main::Clear
+            }],
+            changes.conditions
+        );
+    }
+}
@@ -0,0 +1,123 @@
+//! A [`CacheStore`] that keeps responses on disk, so tools keep working offline with the last
+//! response they received.
+//!
+//! Each response is a JSON file in the cache directory, named by a hash of its [`CacheKey`] and
+//! holding its expiry alongside the response.
+
+use crate::{
+    cache::{CacheKey, CacheStore},
+    Weather,
+};
+use jiff::{SignedDuration, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::Duration,
+};
+
+#[derive(Deserialize, Serialize)]
+struct Entry<W> {
+    expires: Timestamp,
+    weather: W,
+}
+
+/// Cache of responses in a directory
+///
+/// A cache is best effort: responses that can't be read count as missing, and failures to write
+/// are ignored. Use [`DiskCache::write`] to handle them.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// A cache in `dir`, which is created when the first response is stored
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// The response stored for `key`, even if it's expired, with its expiry
+    pub fn read_stale(&self, key: &CacheKey) -> Option<(Weather, Timestamp)> {
+        let json = fs::read_to_string(self.path(key)).ok()?;
+        let entry: Entry<Weather> = serde_json::from_str(&json).ok()?;
+        Some((entry.weather, entry.expires))
+    }
+
+    /// Store a response for `key` for `ttl`
+    pub fn write(&self, key: &CacheKey, weather: &Weather, ttl: Duration) -> io::Result<()> {
+        let ttl = SignedDuration::try_from(ttl).unwrap_or(SignedDuration::MAX);
+        let entry = Entry {
+            expires: Timestamp::now().checked_add(ttl).unwrap_or(Timestamp::MAX),
+            weather,
+        };
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(key), serde_json::to_vec(&entry)?)
+    }
+
+    fn path(&self, key: &CacheKey) -> PathBuf {
+        self.dir.join(format!("{:016x}.json", key.stable_hash()))
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get(&self, key: &CacheKey) -> Option<Arc<Weather>> {
+        let weather = self
+            .read_stale(key)
+            .filter(|(_, expires)| Timestamp::now() < *expires)
+            .map(|(weather, _)| Arc::new(weather));
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            lat = key.lat(),
+            lon = key.lon(),
+            hit = weather.is_some(),
+            "disk cache lookup"
+        );
+        weather
+    }
+
+    fn put(&mut self, key: CacheKey, weather: Arc<Weather>, ttl: Duration) {
+        #[cfg_attr(not(feature = "tracing"), allow(unused_variables))]
+        if let Err(e) = self.write(&key, &weather, ttl) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!(error = %e, dir = %self.dir.display(), "couldn't write to disk cache");
+        }
+    }
+
+    fn invalidate(&mut self, key: &CacheKey) {
+        let _ = fs::remove_file(self.path(key));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Units;
+
+    #[test]
+    fn persist_responses() {
+        let weather = crate::fixtures::full();
+        let dir = std::env::temp_dir().join(format!("owm-rs-disk-cache-{}", std::process::id()));
+        let key = CacheKey::new(49.8951, -97.1384, Units::Metric, "en", 2);
+        let stale = CacheKey::new(0.0, 0.0, Units::Metric, "en", 2);
+
+        let mut cache = DiskCache::new(&dir);
+        cache.put(key.clone(), Arc::new(weather), Duration::from_secs(600));
+        let read = DiskCache::new(&dir).get(&key).unwrap();
+        assert_eq!(49.8951, read.lat);
+
+        let weather = Arc::into_inner(read).unwrap();
+        cache.put(stale.clone(), Arc::new(weather), Duration::ZERO);
+        assert!(cache.get(&stale).is_none());
+        assert!(cache.read_stale(&stale).is_some());
+
+        cache.invalidate(&key);
+        assert!(cache.get(&key).is_none());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,221 @@
+//! One-line, human-readable summaries of the models.
+//!
+//! [`Current`], [`Hourly`], [`Daily`], and [`Alert`] implement `Display` with
+//! [`Units::default`] and [`FormatPolicy::default`]:
+//!
+//! ```text
+//! 270K, feels like 265K, light snow, wind 4 m/s NW
+//! ```
+//!
+//! For responses requested in other units, or with other formatting, use `display`:
+//!
+//! ```
+//! # use owm_rs::{format::FormatPolicy, Units};
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../fixtures/onecall.json"))?;
+//! let current = weather.current.unwrap();
+//! println!("{}", current.display(Units::Metric, &FormatPolicy::default()));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{format::FormatPolicy, units::WindSpeed, Alert, Current, Daily, Hourly, Units};
+use std::fmt;
+
+/// A model formatted as a one-line summary, as returned by `display`
+#[derive(Debug, Clone, Copy)]
+pub struct Summary<'a, T> {
+    item: &'a T,
+    units: Units,
+    policy: &'a FormatPolicy,
+}
+
+impl Current {
+    /// One-line summary, in the `units` of the request
+    pub fn display<'a>(&'a self, units: Units, policy: &'a FormatPolicy) -> Summary<'a, Self> {
+        Summary {
+            item: self,
+            units,
+            policy,
+        }
+    }
+}
+
+impl Hourly {
+    /// One-line summary, in the `units` of the request
+    pub fn display<'a>(&'a self, units: Units, policy: &'a FormatPolicy) -> Summary<'a, Self> {
+        Summary {
+            item: self,
+            units,
+            policy,
+        }
+    }
+}
+
+impl Daily {
+    /// One-line summary, in the `units` of the request
+    pub fn display<'a>(&'a self, units: Units, policy: &'a FormatPolicy) -> Summary<'a, Self> {
+        Summary {
+            item: self,
+            units,
+            policy,
+        }
+    }
+}
+
+/// Parts of a summary, joined with `", "`
+struct Parts(Vec<String>);
+
+impl Parts {
+    fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn push(&mut self, part: impl Into<String>) {
+        let part = part.into();
+        if !part.is_empty() {
+            self.0.push(part);
+        }
+    }
+
+    fn wind(&mut self, policy: &FormatPolicy, speed: WindSpeed, deg: u16) {
+        let direction = crate::wind::CompassDirection::from(deg);
+        self.push(format!("wind {} {direction}", policy.wind_speed(speed)));
+    }
+
+    fn pop(&mut self, policy: &FormatPolicy, pop: Option<f64>) {
+        if let Some(pop) = pop {
+            self.push(format!(
+                "{} chance of precipitation",
+                policy.percentage(pop)
+            ));
+        }
+    }
+}
+
+impl fmt::Display for Parts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0.join(", "))
+    }
+}
+
+/// Description of the primary weather condition, if any
+fn description(weather: &[crate::WeatherElement]) -> &str {
+    weather.first().map_or("", |x| &x.description)
+}
+
+impl fmt::Display for Summary<'_, Current> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, units, policy) = (self.item, self.units, self.policy);
+        let mut parts = Parts::new();
+        parts.push(policy.temperature(x.temperature(units)));
+        parts.push(format!(
+            "feels like {}",
+            policy.temperature(x.feels_like_temperature(units))
+        ));
+        parts.push(description(&x.weather));
+        parts.wind(policy, x.wind(units), x.wind_deg);
+        parts.fmt(f)
+    }
+}
+
+impl fmt::Display for Summary<'_, Hourly> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, units, policy) = (self.item, self.units, self.policy);
+        let mut parts = Parts::new();
+        parts.push(policy.temperature(x.temperature(units)));
+        parts.push(format!(
+            "feels like {}",
+            policy.temperature(x.feels_like_temperature(units))
+        ));
+        parts.push(description(&x.weather));
+        parts.pop(policy, x.pop);
+        parts.wind(policy, x.wind(units), x.wind_deg);
+        parts.fmt(f)
+    }
+}
+
+impl fmt::Display for Summary<'_, Daily> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (x, units, policy) = (self.item, self.units, self.policy);
+        let temperature = |value| policy.temperature(crate::units::Temperature::new(value, units));
+        let mut parts = Parts::new();
+        parts.push(format!("high {}", temperature(x.temp.max)));
+        parts.push(format!("low {}", temperature(x.temp.min)));
+        parts.push(description(&x.weather));
+        parts.pop(policy, x.pop);
+        parts.wind(policy, WindSpeed::new(x.wind_speed, units), x.wind_deg);
+        parts.fmt(f)
+    }
+}
+
+impl fmt::Display for Current {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(Units::default(), &FormatPolicy::default())
+            .fmt(f)
+    }
+}
+
+impl fmt::Display for Hourly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(Units::default(), &FormatPolicy::default())
+            .fmt(f)
+    }
+}
+
+impl fmt::Display for Daily {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.display(Units::default(), &FormatPolicy::default())
+            .fmt(f)
+    }
+}
+
+impl fmt::Display for Alert {
+    /// The event, who issued it, and when it ends
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} from {} until {}",
+            self.event,
+            self.sender_name,
+            self.end.strftime("%Y-%m-%d %H:%M %Z")
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summaries() {
+        let weather = crate::fixtures::full();
+        let policy = FormatPolicy::default();
+
+        let current = weather.current.unwrap();
+        assert_eq!(
+            "27°C, feels like 28°C, scattered clouds, wind 6 m/s NW",
+            current.display(Units::Metric, &policy).to_string()
+        );
+        assert_eq!(
+            current.display(Units::Standard, &policy).to_string(),
+            current.to_string()
+        );
+
+        let hourly = &weather.hourly.unwrap()[0];
+        assert_eq!(
+            "28°C, feels like 29°C, scattered clouds, 12% chance of precipitation, wind 6 m/s NW",
+            hourly.display(Units::Metric, &policy).to_string()
+        );
+
+        let daily = &weather.daily.unwrap()[0];
+        assert_eq!(
+            "high 28°C, low 17°C, moderate rain, 81% chance of precipitation, wind 7 m/s WNW",
+            daily.display(Units::Metric, &policy).to_string()
+        );
+
+        let alert = &weather.alerts.unwrap()[0];
+        assert_eq!(
+            "severe thunderstorm watch from Environment Canada until 2024-07-21 23:00 UTC",
+            alert.to_string()
+        );
+    }
+}
@@ -0,0 +1,66 @@
+//! Emoji for weather conditions, for status bars and chat bots.
+//!
+//! [`condition`] maps a condition id to its daytime emoji. [`WeatherElement::emoji`] also uses
+//! the icon to pick a night emoji, such as 🌙 instead of ☀️ for a clear sky.
+//!
+//! [`WeatherElement::emoji`]: crate::WeatherElement::emoji
+
+/// Emoji for a weather condition id during the day, or `None` for an unknown id
+pub fn condition(id: i64) -> Option<&'static str> {
+    Some(match id {
+        200..=299 => "⛈️",
+        300..=399 => "🌦️",
+        511 => "🌨️",
+        500..=599 => "🌧️",
+        611..=616 => "🌨️",
+        600..=699 => "❄️",
+        762 => "🌋",
+        771 => "💨",
+        781 => "🌪️",
+        731 | 751 | 761 => "💨",
+        700..=799 => "🌫️",
+        800 => "☀️",
+        801 => "🌤️",
+        802 => "⛅",
+        803 => "🌥️",
+        804 => "☁️",
+        _ => return None,
+    })
+}
+
+/// Emoji for a weather condition id at night, or `None` for an unknown id
+///
+/// Only clear and partly cloudy skies differ from [`condition`].
+pub fn night(id: i64) -> Option<&'static str> {
+    match id {
+        800 => Some("🌙"),
+        801..=803 => Some("☁️"),
+        _ => condition(id),
+    }
+}
+
+/// Emoji for a condition, using the icon's `d` or `n` suffix to tell day from night
+pub(crate) fn for_element(id: i64, icon: &str) -> Option<&'static str> {
+    if icon.ends_with('n') {
+        night(id)
+    } else {
+        condition(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn emoji() {
+        assert_eq!(Some("⛈️"), condition(211));
+        assert_eq!(Some("🌨️"), condition(511));
+        assert_eq!(Some("🌧️"), condition(502));
+        assert_eq!(Some("🌫️"), condition(741));
+        assert_eq!(Some("☀️"), for_element(800, "01d"));
+        assert_eq!(Some("🌙"), for_element(800, "01n"));
+        assert_eq!(Some("❄️"), for_element(601, "13n"));
+        assert_eq!(None, condition(900));
+    }
+}
@@ -0,0 +1,204 @@
+//! One error type for everything that can go wrong between making a call and getting a model.
+
+use crate::{ErrorKind, OwmError};
+use std::{fmt, time::Duration};
+
+/// Bytes of the response shown on each side of a deserialization error
+const SNIPPET_CONTEXT: usize = 40;
+
+/// Failure to get a response model
+#[derive(Debug)]
+pub enum Error {
+    /// The request couldn't be sent, or the response couldn't be read
+    Transport(Box<dyn std::error::Error + Send + Sync>),
+
+    /// Missing, invalid, or not yet activated API key (401)
+    InvalidApiKey,
+
+    /// The API key's subscription doesn't include the endpoint, such as One Call 3.0
+    SubscriptionRequired,
+
+    /// No data for the requested location or resource, such as out of range coordinates (404)
+    NotFound,
+
+    /// Call limit of the subscription exceeded (429)
+    RateLimited {
+        /// Time to wait from the response's `Retry-After` header, if it had one
+        retry_after: Option<Duration>,
+    },
+
+    /// Any other unsuccessful HTTP status, without an error body from OWM
+    Status(u16),
+
+    /// No calls left in the rate limiter's quota
+    RateLimitExhausted {
+        /// Time until a call is available
+        wait: Duration,
+    },
+
+    /// The response isn't the expected JSON
+    Deserialize {
+        source: serde_json::Error,
+
+        /// Byte offset of the error in the response
+        offset: usize,
+
+        /// The response around the error
+        snippet: String,
+    },
+
+    /// Any other error body returned by OWM
+    Api(OwmError),
+}
+
+impl Error {
+    /// Wrap an error from the HTTP client
+    pub fn transport(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Self {
+        Self::Transport(e.into())
+    }
+
+    /// Error for an unsuccessful response with HTTP `status` and `body`. `retry_after` is its
+    /// parsed `Retry-After` header, which can be parsed with
+    /// [`parse_retry_after`](crate::retry::parse_retry_after).
+    pub fn from_response(status: u16, body: &str, retry_after: Option<Duration>) -> Self {
+        let error = match serde_json::from_str::<OwmError>(body) {
+            Ok(e) => Self::from(e),
+            Err(_) => match status {
+                401 => Self::InvalidApiKey,
+                403 => Self::SubscriptionRequired,
+                404 => Self::NotFound,
+                429 => Self::RateLimited { retry_after: None },
+                _ => Self::Status(status),
+            },
+        };
+        match error {
+            Self::RateLimited { .. } => Self::RateLimited { retry_after },
+            e => e,
+        }
+    }
+
+    /// Locate a deserialization error in the `json` it came from
+    pub fn deserialize(source: serde_json::Error, json: &str) -> Self {
+        let line_start: usize = json
+            .split_inclusive('\n')
+            .take(source.line().saturating_sub(1))
+            .map(str::len)
+            .sum();
+        let offset = (line_start + source.column().saturating_sub(1)).min(json.len());
+
+        let floor = |mut i: usize| {
+            while !json.is_char_boundary(i) {
+                i -= 1;
+            }
+            i
+        };
+        let start = floor(offset.saturating_sub(SNIPPET_CONTEXT));
+        let end = floor((offset + SNIPPET_CONTEXT).min(json.len()));
+        Self::Deserialize {
+            source,
+            offset,
+            snippet: json[start..end].to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Transport(e) => write!(f, "request failed: {e}"),
+            Self::InvalidApiKey => write!(f, "invalid API key"),
+            Self::SubscriptionRequired => write!(f, "subscription doesn't include this endpoint"),
+            Self::NotFound => write!(f, "no data for the requested location"),
+            Self::RateLimited {
+                retry_after: Some(wait),
+            } => write!(f, "rate limited, retry in {wait:?}"),
+            Self::RateLimited { retry_after: None } => write!(f, "rate limited"),
+            Self::Status(status) => write!(f, "unsuccessful HTTP status {status}"),
+            Self::RateLimitExhausted { wait } => {
+                write!(f, "rate limit exhausted, next call in {wait:?}")
+            }
+            Self::Deserialize {
+                source,
+                offset,
+                snippet,
+            } => write!(
+                f,
+                "invalid response at byte {offset} ({snippet:?}): {source}"
+            ),
+            Self::Api(e) => e.fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Transport(e) => Some(e.as_ref()),
+            Self::Deserialize { source, .. } => Some(source),
+            Self::Api(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<OwmError> for Error {
+    /// The typed variant for the error's [`kind`](OwmError::kind), or [`Error::Api`]
+    fn from(e: OwmError) -> Self {
+        match e.kind() {
+            ErrorKind::InvalidApiKey => Self::InvalidApiKey,
+            ErrorKind::SubscriptionRequired => Self::SubscriptionRequired,
+            ErrorKind::NotFound => Self::NotFound,
+            ErrorKind::QuotaExceeded => Self::RateLimited { retry_after: None },
+            _ => Self::Api(e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Weather;
+
+    #[test]
+    fn locate_deserialize_error() {
+        let json =
+            crate::fixtures::FULL_JSON.replacen("\"temp\": ", "\"temp\": \"hot\", \"x\": ", 1);
+        let e = serde_json::from_str::<Weather>(&json).unwrap_err();
+        let Error::Deserialize {
+            offset, snippet, ..
+        } = Error::deserialize(e, &json)
+        else {
+            panic!("not a deserialization error");
+        };
+        // serde_json reports the error at the closing quote of the string
+        assert_eq!(json.find("\"hot\"").unwrap() + 4, offset);
+        assert!(snippet.contains("\"temp\": \"hot\""));
+    }
+
+    #[test]
+    fn status_errors() {
+        let one_call = r#"{ "cod": 401, "message": "Please note that using One Call 3.0 requires a separate subscription to the One Call by Call plan." }"#;
+        assert!(matches!(
+            Error::from_response(401, one_call, None),
+            Error::SubscriptionRequired
+        ));
+        assert!(matches!(
+            Error::from_response(401, "Unauthorized", None),
+            Error::InvalidApiKey
+        ));
+        let wait = Some(Duration::from_secs(60));
+        assert!(matches!(
+            Error::from_response(429, r#"{ "cod": 429, "message": "Too many requests" }"#, wait),
+            Error::RateLimited { retry_after } if retry_after == wait
+        ));
+        let bad_request = r#"{ "cod": "400", "message": "wrong latitude" }"#;
+        assert!(matches!(
+            Error::from_response(400, bad_request, None),
+            Error::Api(_)
+        ));
+        assert!(matches!(
+            Error::from_response(502, "<html>", None),
+            Error::Status(502)
+        ));
+    }
+}
@@ -0,0 +1,227 @@
+//! Export of hourly and daily forecasts to CSV, for spreadsheets and data frames.
+//!
+//! [`to_csv`] writes a header followed by one row per entry. Times are RFC 3339 timestamps in
+//! UTC, and values missing from the response are left empty. Columns come in the order of
+//! [`HOURLY_COLUMNS`] or [`DAILY_COLUMNS`]; new columns are only ever added at the end.
+//!
+//! ```
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../fixtures/onecall.json"))?;
+//! let mut csv = Vec::new();
+//! owm_rs::export::to_csv(&mut csv, weather.hourly.as_deref().unwrap_or_default())?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! [`influx`] writes current conditions and hourly forecasts in the InfluxDB line protocol.
+
+use crate::{Daily, Hourly, WeatherElement};
+use jiff::Zoned;
+use std::io;
+
+pub mod influx;
+
+/// Columns of hourly exports. `rain` and `snow` are volumes for the hour, mm, and the
+/// `weather_*` columns describe the primary condition.
+pub const HOURLY_COLUMNS: &[&str] = &[
+    "dt",
+    "temp",
+    "feels_like",
+    "pressure",
+    "humidity",
+    "dew_point",
+    "uvi",
+    "clouds",
+    "visibility",
+    "wind_speed",
+    "wind_gust",
+    "wind_deg",
+    "pop",
+    "rain",
+    "snow",
+    "weather_id",
+    "weather_main",
+    "weather_description",
+];
+
+/// Columns of daily exports. `rain` and `snow` are volumes for the day, mm, and the
+/// `weather_*` columns describe the primary condition.
+pub const DAILY_COLUMNS: &[&str] = &[
+    "dt",
+    "sunrise",
+    "sunset",
+    "moonrise",
+    "moonset",
+    "moon_phase",
+    "temp_min",
+    "temp_max",
+    "temp_morn",
+    "temp_day",
+    "temp_eve",
+    "temp_night",
+    "feels_like_morn",
+    "feels_like_day",
+    "feels_like_eve",
+    "feels_like_night",
+    "pressure",
+    "humidity",
+    "dew_point",
+    "wind_speed",
+    "wind_gust",
+    "wind_deg",
+    "clouds",
+    "uvi",
+    "pop",
+    "rain",
+    "snow",
+    "weather_id",
+    "weather_main",
+    "weather_description",
+    "summary",
+];
+
+/// An entry that can be exported as a CSV row
+pub trait CsvRecord {
+    /// Column names, in order
+    const COLUMNS: &'static [&'static str];
+
+    /// Values of the columns, in order. Missing values are empty.
+    fn record(&self) -> Vec<String>;
+}
+
+/// Write `rows` as CSV to `w`, with a header row
+pub fn to_csv<W: io::Write, T: CsvRecord>(mut w: W, rows: &[T]) -> io::Result<()> {
+    write_row(&mut w, T::COLUMNS.iter().copied())?;
+    for row in rows {
+        write_row(&mut w, row.record().iter().map(String::as_str))?;
+    }
+    w.flush()
+}
+
+fn write_row<'a>(w: &mut impl io::Write, fields: impl Iterator<Item = &'a str>) -> io::Result<()> {
+    for (i, field) in fields.enumerate() {
+        if i > 0 {
+            w.write_all(b",")?;
+        }
+        if field.contains([',', '"', '\n', '\r']) {
+            write!(w, "\"{}\"", field.replace('"', "\"\""))?;
+        } else {
+            w.write_all(field.as_bytes())?;
+        }
+    }
+    w.write_all(b"\n")
+}
+
+fn time(dt: &Zoned) -> String {
+    dt.timestamp().to_string()
+}
+
+fn optional<T: ToString>(value: Option<T>) -> String {
+    value.map_or_else(String::new, |x| x.to_string())
+}
+
+fn condition(weather: &[WeatherElement]) -> [String; 3] {
+    match weather.first() {
+        Some(x) => [
+            x.id.to_string(),
+            format!("{:?}", x.main),
+            x.description.clone(),
+        ],
+        None => Default::default(),
+    }
+}
+
+impl CsvRecord for Hourly {
+    const COLUMNS: &'static [&'static str] = HOURLY_COLUMNS;
+
+    fn record(&self) -> Vec<String> {
+        let mut record = vec![
+            time(&self.dt),
+            self.temp.to_string(),
+            self.feels_like.to_string(),
+            self.pressure.to_string(),
+            self.humidity.to_string(),
+            self.dew_point.to_string(),
+            optional(self.uvi),
+            self.clouds.to_string(),
+            optional(self.visibility),
+            self.wind_speed.to_string(),
+            optional(self.wind_gust),
+            self.wind_deg.to_string(),
+            optional(self.pop),
+            optional(self.rain.as_ref().map(|x| x.volume().0)),
+            optional(self.snow.as_ref().map(|x| x.volume().0)),
+        ];
+        record.extend(condition(&self.weather));
+        record
+    }
+}
+
+impl CsvRecord for Daily {
+    const COLUMNS: &'static [&'static str] = DAILY_COLUMNS;
+
+    fn record(&self) -> Vec<String> {
+        let mut record = vec![
+            time(&self.dt),
+            optional(self.sunrise.as_ref().map(time)),
+            optional(self.sunset.as_ref().map(time)),
+            time(&self.moonrise),
+            time(&self.moonset),
+            self.moon_phase.to_string(),
+            self.temp.min.to_string(),
+            self.temp.max.to_string(),
+            self.temp.morn.to_string(),
+            self.temp.day.to_string(),
+            self.temp.eve.to_string(),
+            self.temp.night.to_string(),
+            self.feels_like.morn.to_string(),
+            self.feels_like.day.to_string(),
+            self.feels_like.eve.to_string(),
+            self.feels_like.night.to_string(),
+            self.pressure.to_string(),
+            self.humidity.to_string(),
+            self.dew_point.to_string(),
+            self.wind_speed.to_string(),
+            optional(self.wind_gust),
+            self.wind_deg.to_string(),
+            self.clouds.to_string(),
+            self.uvi.to_string(),
+            optional(self.pop),
+            optional(self.rain),
+            optional(self.snow),
+        ];
+        record.extend(condition(&self.weather));
+        record.push(self.summary.clone().unwrap_or_default());
+        record
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn export_csv() {
+        let weather = crate::fixtures::full();
+        let hourly = weather.hourly.unwrap();
+        let daily = weather.daily.unwrap();
+        assert_eq!(HOURLY_COLUMNS.len(), hourly[0].record().len());
+        assert_eq!(DAILY_COLUMNS.len(), daily[0].record().len());
+
+        let mut csv = Vec::new();
+        to_csv(&mut csv, &hourly[..1]).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(Some(HOURLY_COLUMNS.join(",").as_str()), lines.next());
+        assert_eq!(
+            Some("2024-07-21T17:00:00Z,27.9,28.5,1009,50,16.52,7.9,40,10000,5.66,9.26,318,0.12,,,802,Clouds,scattered clouds"),
+            lines.next()
+        );
+        assert_eq!(None, lines.next());
+
+        let mut row = Vec::new();
+        write_row(&mut row, ["a", "b,c", "say \"hi\""].into_iter()).unwrap();
+        assert_eq!(
+            "a,\"b,c\",\"say \"\"hi\"\"\"\n",
+            String::from_utf8(row).unwrap()
+        );
+    }
+}
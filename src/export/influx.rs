@@ -0,0 +1,213 @@
+//! Export of current conditions and hourly forecasts to the InfluxDB
+//! [line protocol](https://docs.influxdata.com/influxdb/v2/reference/syntax/line-protocol/).
+//!
+//! Each point is tagged with `series=current` or `series=hourly`, so observations and forecasts
+//! for the same time can be told apart, along with the tags passed in. Values missing from the
+//! response are left out of the point, and timestamps are in nanoseconds.
+//!
+//! ```
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../../fixtures/onecall.json"))?;
+//! use owm_rs::export::influx;
+//!
+//! let lines = influx::to_line_protocol(&weather, "weather", &[("location", "Winnipeg")]);
+//! assert!(lines.starts_with("weather,location=Winnipeg,series=current temp="));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{Current, Hourly, Precipitation, Weather, WeatherElement};
+use jiff::Zoned;
+use std::fmt::Write;
+
+/// Points for the current conditions and each hourly forecast of `weather`, one per line
+///
+/// Tags with an empty key or value are left out, since the line protocol can't represent them.
+pub fn to_line_protocol(weather: &Weather, measurement: &str, tags: &[(&str, &str)]) -> String {
+    let mut lines = String::new();
+    if let Some(current) = &weather.current {
+        write_point(
+            &mut lines,
+            measurement,
+            tags,
+            "current",
+            current_fields(current),
+        );
+    }
+    for hourly in weather.hourly.iter().flatten() {
+        write_point(
+            &mut lines,
+            measurement,
+            tags,
+            "hourly",
+            hourly_fields(hourly),
+        );
+    }
+    lines
+}
+
+enum Value<'a> {
+    Float(f64),
+    Integer(i64),
+    String(&'a str),
+}
+
+/// Fields of a point, and its time
+struct Fields<'a> {
+    dt: &'a Zoned,
+    values: Vec<(&'static str, Value<'a>)>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(dt: &'a Zoned) -> Self {
+        Self {
+            dt,
+            values: Vec::new(),
+        }
+    }
+
+    fn float(&mut self, key: &'static str, value: Option<f64>) {
+        // The line protocol has no representation for NaN or infinity
+        if let Some(value) = value.filter(|x| x.is_finite()) {
+            self.values.push((key, Value::Float(value)));
+        }
+    }
+
+    fn integer(&mut self, key: &'static str, value: Option<impl Into<i64>>) {
+        if let Some(value) = value {
+            self.values.push((key, Value::Integer(value.into())));
+        }
+    }
+
+    fn volume(&mut self, key: &'static str, precipitation: &Option<Precipitation>) {
+        self.float(key, precipitation.as_ref().map(|x| x.volume().0));
+    }
+
+    fn condition(&mut self, weather: &'a [WeatherElement]) {
+        if let Some(x) = weather.first() {
+            self.integer("weather_id", Some(x.id));
+            self.values
+                .push(("weather_description", Value::String(&x.description)));
+        }
+    }
+}
+
+fn current_fields(current: &Current) -> Fields<'_> {
+    let mut fields = Fields::new(&current.dt);
+    fields.float("temp", Some(current.temp));
+    fields.float("feels_like", Some(current.feels_like));
+    fields.integer("pressure", Some(current.pressure));
+    fields.integer("humidity", Some(current.humidity));
+    fields.float("dew_point", Some(current.dew_point));
+    fields.float("uvi", Some(current.uvi));
+    fields.integer("clouds", Some(current.clouds));
+    fields.integer("visibility", current.visibility);
+    fields.float("wind_speed", Some(current.wind_speed));
+    fields.float("wind_gust", current.wind_gust);
+    fields.integer("wind_deg", Some(current.wind_deg));
+    fields.volume("rain", &current.rain);
+    fields.volume("snow", &current.snow);
+    fields.condition(&current.weather);
+    fields
+}
+
+fn hourly_fields(hourly: &Hourly) -> Fields<'_> {
+    let mut fields = Fields::new(&hourly.dt);
+    fields.float("temp", Some(hourly.temp));
+    fields.float("feels_like", Some(hourly.feels_like));
+    fields.integer("pressure", Some(hourly.pressure));
+    fields.integer("humidity", Some(hourly.humidity));
+    fields.float("dew_point", Some(hourly.dew_point));
+    fields.float("uvi", hourly.uvi);
+    fields.integer("clouds", Some(hourly.clouds));
+    fields.integer("visibility", hourly.visibility);
+    fields.float("wind_speed", Some(hourly.wind_speed));
+    fields.float("wind_gust", hourly.wind_gust);
+    fields.integer("wind_deg", Some(hourly.wind_deg));
+    fields.float("pop", hourly.pop);
+    fields.volume("rain", &hourly.rain);
+    fields.volume("snow", &hourly.snow);
+    fields.condition(&hourly.weather);
+    fields
+}
+
+fn write_point(
+    lines: &mut String,
+    measurement: &str,
+    tags: &[(&str, &str)],
+    series: &str,
+    fields: Fields,
+) {
+    let mut tags: Vec<(&str, &str)> = tags
+        .iter()
+        .copied()
+        .filter(|(key, value)| !key.is_empty() && !value.is_empty())
+        .chain([("series", series)])
+        .collect();
+    // InfluxDB recommends sorting tags by key
+    tags.sort_by_key(|x| x.0);
+
+    lines.push_str(&escape(measurement, &[',', ' ']));
+    for (key, value) in tags {
+        let _ = write!(lines, ",{}={}", key_escape(key), key_escape(value));
+    }
+    for (i, (key, value)) in fields.values.iter().enumerate() {
+        lines.push(if i == 0 { ' ' } else { ',' });
+        let _ = match value {
+            Value::Float(x) => write!(lines, "{}={x}", key_escape(key)),
+            Value::Integer(x) => write!(lines, "{}={x}i", key_escape(key)),
+            Value::String(x) => write!(lines, "{}=\"{}\"", key_escape(key), escape(x, &['"'])),
+        };
+    }
+    let _ = writeln!(lines, " {}", fields.dt.timestamp().as_nanosecond());
+}
+
+/// Escape a tag key, tag value, or field key
+fn key_escape(text: &str) -> String {
+    escape(text, &[',', '=', ' '])
+}
+
+/// Escape backslashes and `special` characters with a backslash, and replace line breaks, which
+/// can't be escaped, with spaces
+fn escape(text: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\n' | '\r' => escaped.push_str(&escape(" ", special)),
+            '\\' => escaped.push_str("\\\\"),
+            c if special.contains(&c) => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_protocol() {
+        let weather = crate::fixtures::full();
+        let lines = to_line_protocol(&weather, "owm weather", &[("site", "a,b=c"), ("x", "")]);
+        let lines: Vec<&str> = lines.lines().collect();
+        assert_eq!(1 + weather.hourly.as_ref().unwrap().len(), lines.len());
+
+        let current = weather.current.as_ref().unwrap();
+        assert!(
+            lines[0].starts_with(&format!(
+                r"owm\ weather,series=current,site=a\,b\=c temp={},",
+                current.temp
+            )),
+            "{}",
+            lines[0]
+        );
+        assert!(lines[0].contains(&format!(",humidity={}i,", current.humidity)));
+        assert!(lines[0].ends_with(&format!(" {}", current.dt.timestamp().as_nanosecond())));
+        assert!(lines[1].contains(",series=hourly,"));
+
+        assert_eq!(r#"say \"hi\" \\o/ "#, escape("say \"hi\" \\o/\n", &['"']));
+        assert_eq!(r"a\ b", key_escape("a\nb"));
+    }
+}
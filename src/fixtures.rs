@@ -0,0 +1,84 @@
+//! Sample One Call responses for testing without the network.
+//!
+//! Each sample is available as raw JSON, to test your own parsing or caching, and parsed into a
+//! [`Weather`]. All are in metric units.
+
+use crate::Weather;
+
+/// Every section, for Winnipeg in July, with one alert
+pub const FULL_JSON: &str = include_str!("../fixtures/onecall.json");
+
+/// Only the current and daily sections, as requested with `exclude=minutely,hourly,alerts`
+pub const EXCLUDED_SECTIONS_JSON: &str = include_str!("../fixtures/onecall_excluded.json");
+
+/// Svalbard during the midnight sun, where sunrise and sunset are reported as `0`, and parsed as
+/// `None`
+pub const POLAR_JSON: &str = include_str!("../fixtures/onecall_polar.json");
+
+/// Des Moines on a hot afternoon, with a US National Weather Service advisory and watch
+pub const ALERTS_JSON: &str = include_str!("../fixtures/onecall_alerts.json");
+
+/// Auckland in summer, at UTC+13, where each day's local noon falls on the previous day in UTC.
+/// Only the daily section.
+#[cfg(test)]
+pub(crate) const AUCKLAND_JSON: &str = include_str!("../fixtures/onecall_auckland.json");
+
+/// [`FULL_JSON`], parsed
+pub fn full() -> Weather {
+    parse(FULL_JSON)
+}
+
+/// [`EXCLUDED_SECTIONS_JSON`], parsed
+pub fn excluded_sections() -> Weather {
+    parse(EXCLUDED_SECTIONS_JSON)
+}
+
+/// [`POLAR_JSON`], parsed
+pub fn polar() -> Weather {
+    parse(POLAR_JSON)
+}
+
+/// [`ALERTS_JSON`], parsed
+pub fn alerts() -> Weather {
+    parse(ALERTS_JSON)
+}
+
+/// [`AUCKLAND_JSON`], parsed
+#[cfg(test)]
+pub(crate) fn auckland() -> Weather {
+    parse(AUCKLAND_JSON)
+}
+
+fn parse(json: &str) -> Weather {
+    serde_json::from_str(json).expect("bundled fixtures are valid")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_fixtures() {
+        assert!(full().minutely.is_some());
+
+        let weather = excluded_sections();
+        assert!(weather.hourly.is_none() && weather.alerts.is_none());
+        let daily = weather.daily.unwrap();
+        assert_eq!(3, daily.len());
+        assert_eq!(
+            Some("There will be clear sky today"),
+            daily[1].summary.as_deref()
+        );
+
+        assert!(polar().current.unwrap().sunrise.is_none());
+
+        assert_eq!(2, auckland().daily.unwrap().len());
+
+        let weather = alerts();
+        let advisory = weather.alerts.as_ref().unwrap()[0].parsed();
+        assert_eq!(
+            Some("Until 8 PM CDT this evening."),
+            advisory.when.as_deref()
+        );
+    }
+}
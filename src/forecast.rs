@@ -0,0 +1,188 @@
+//! Models for the 5 day / 3 hour forecast API: <https://openweathermap.org/forecast5>
+
+use crate::{
+    current_weather::{Clouds, Coord, MainParameters, Wind},
+    ts_seconds, Precipitation, WeatherElement,
+};
+use jiff::Zoned;
+use serde::{Deserialize, Serialize};
+
+/// 5 day / 3 hour forecast API response (`/data/2.5/forecast`)
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct Forecast {
+    /// Internal parameter
+    pub cod: String,
+
+    /// Internal parameter
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub message: f64,
+
+    /// A number of timestamps returned in the API response
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub cnt: u8,
+
+    /// Forecast steps, 3 hours apart
+    pub list: Vec<ForecastStep>,
+
+    pub city: City,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+/// A single 3 hour forecast step
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct ForecastStep {
+    /// Time of data forecasted, unix, UTC
+    #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub dt: Zoned,
+
+    pub main: MainParameters,
+
+    pub weather: Vec<WeatherElement>,
+
+    pub clouds: Clouds,
+
+    pub wind: Wind,
+
+    /// Average visibility, metres. Usually at most 10km, though larger values are sometimes reported
+    #[serde(default, deserialize_with = "crate::integer::option::deserialize")]
+    pub visibility: Option<u32>,
+
+    /// Probability of precipitation. The values of the parameter vary between 0 and 1, where 0 is equal to 0%, 1 is equal to 100%
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    pub pop: f64,
+
+    /// (where available) Rain volume for last 3 hours, mm
+    #[serde(
+        default,
+        deserialize_with = "crate::lenient::precipitation::three_hours"
+    )]
+    pub rain: Option<Precipitation>,
+
+    /// (where available) Snow volume for last 3 hours, mm
+    #[serde(
+        default,
+        deserialize_with = "crate::lenient::precipitation::three_hours"
+    )]
+    pub snow: Option<Precipitation>,
+
+    pub sys: ForecastSys,
+
+    /// Time of data forecasted, ISO, UTC
+    pub dt_txt: String,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct ForecastSys {
+    /// Part of the day
+    pub pod: PartOfDay,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub enum PartOfDay {
+    #[serde(rename = "d")]
+    Day,
+
+    #[serde(rename = "n")]
+    Night,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+pub struct City {
+    /// City ID
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub id: i64,
+
+    /// City name
+    pub name: String,
+
+    pub coord: Coord,
+
+    /// Country code (GB, JP etc.)
+    pub country: String,
+
+    /// City population
+    #[serde(default, deserialize_with = "crate::integer::option::deserialize")]
+    pub population: Option<u64>,
+
+    /// Shift in seconds from UTC
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    pub timezone: i32,
+
+    /// Sunrise time, unix, UTC
+    #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub sunrise: Zoned,
+
+    /// Sunset time, unix, UTC
+    #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
+    pub sunset: Zoned,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_forecast() {
+        let forecast: Forecast =
+            serde_json::from_str(include_str!("../fixtures/forecast.json")).unwrap();
+
+        assert_eq!(2, forecast.list.len());
+        assert_eq!(PartOfDay::Night, forecast.list[1].sys.pod);
+        assert_eq!(
+            Some(0.26),
+            forecast.list[0].rain.as_ref().unwrap().three_hours
+        );
+        assert_eq!(Some(-1.11), forecast.list[0].main.temp_kf);
+        assert_eq!("Zocca", forecast.city.name);
+    }
+
+    #[test]
+    fn wire_format() {
+        let weather: Forecast =
+            serde_json::from_str(include_str!("../fixtures/forecast.json")).unwrap();
+        insta::assert_json_snapshot!(weather);
+    }
+}
@@ -0,0 +1,144 @@
+//! One consistent style for every number the crate formats.
+//!
+//! [`FormatPolicy`] is used by the `Display` implementations of the [`units`](crate::units)
+//! types and the models' one-line summaries (with [`FormatPolicy::default`]), by
+//! [`Current::display`](crate::Current::display) and friends, and by
+//! [`SmsFormat`](crate::sms::SmsFormat).
+
+use crate::units::{Precipitation, Temperature, WindSpeed};
+
+/// How to round a value to the configured number of decimal places
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// 2.5 rounds to 3, -2.5 rounds to -3
+    #[default]
+    HalfAwayFromZero,
+
+    /// 2.5 rounds to 2, 3.5 rounds to 4
+    HalfToEven,
+
+    /// Towards negative infinity
+    Down,
+
+    /// Towards positive infinity
+    Up,
+
+    /// Drop the extra decimals
+    TowardZero,
+}
+
+/// Decimal places, rounding, and labels for formatted quantities
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormatPolicy {
+    pub temperature_decimals: usize,
+    pub wind_speed_decimals: usize,
+    pub precipitation_decimals: usize,
+
+    /// Decimal places of percentages, such as the probability of precipitation
+    pub percentage_decimals: usize,
+
+    pub rounding: Rounding,
+
+    /// Whether to follow values with their unit symbol, such as `"°C"` or `"mm"`
+    pub unit_labels: bool,
+
+    /// Separator between the integer and fractional parts, such as `','` in many European locales
+    pub decimal_separator: char,
+}
+
+impl Default for FormatPolicy {
+    /// Whole temperatures, wind speeds, and percentages, precipitation to one decimal, with unit
+    /// labels and a `'.'` separator
+    fn default() -> Self {
+        Self {
+            temperature_decimals: 0,
+            wind_speed_decimals: 0,
+            precipitation_decimals: 1,
+            percentage_decimals: 0,
+            rounding: Rounding::default(),
+            unit_labels: true,
+            decimal_separator: '.',
+        }
+    }
+}
+
+impl FormatPolicy {
+    /// Round `value` to `decimals` places and format it with the decimal separator. Negative
+    /// zero is formatted as zero.
+    pub fn number(&self, value: f64, decimals: usize) -> String {
+        let scale = 10_f64.powi(decimals as i32);
+        let scaled = value * scale;
+        let rounded = match self.rounding {
+            Rounding::HalfAwayFromZero => scaled.round(),
+            Rounding::HalfToEven => scaled.round_ties_even(),
+            Rounding::Down => scaled.floor(),
+            Rounding::Up => scaled.ceil(),
+            Rounding::TowardZero => scaled.trunc(),
+        } / scale;
+
+        // Adding zero turns -0 into 0
+        let formatted = format!("{:.*}", decimals, rounded + 0.0);
+        if self.decimal_separator == '.' {
+            formatted
+        } else {
+            formatted.replace('.', &self.decimal_separator.to_string())
+        }
+    }
+
+    pub fn temperature(&self, temp: Temperature) -> String {
+        let number = self.number(temp.value, self.temperature_decimals);
+        self.label(number, "", temp.units.temperature_symbol())
+    }
+
+    pub fn wind_speed(&self, speed: WindSpeed) -> String {
+        let number = self.number(speed.value, self.wind_speed_decimals);
+        self.label(number, " ", speed.units.speed_symbol())
+    }
+
+    pub fn precipitation(&self, volume: Precipitation) -> String {
+        let number = self.number(volume.millimetres(), self.precipitation_decimals);
+        self.label(number, " ", "mm")
+    }
+
+    /// Format a fraction from 0 to 1 as a percentage
+    pub fn percentage(&self, fraction: f64) -> String {
+        let number = self.number(fraction * 100.0, self.percentage_decimals);
+        self.label(number, "", "%")
+    }
+
+    fn label(&self, number: String, separator: &str, symbol: &str) -> String {
+        if self.unit_labels {
+            format!("{number}{separator}{symbol}")
+        } else {
+            number
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Units;
+
+    #[test]
+    fn format_quantities() {
+        let temp = Temperature::new(-0.4, Units::Metric);
+        assert_eq!("0°C", temp.to_string());
+        assert_eq!("-0.4°C", format!("{temp:.1}"));
+        assert_eq!("12 mph", WindSpeed::new(12.4, Units::Imperial).to_string());
+        assert_eq!("3.4 mm", Precipitation(3.44).to_string());
+
+        let policy = FormatPolicy {
+            temperature_decimals: 1,
+            rounding: Rounding::HalfToEven,
+            unit_labels: false,
+            decimal_separator: ',',
+            ..FormatPolicy::default()
+        };
+        assert_eq!(
+            "28,2",
+            policy.temperature(Temperature::new(28.25, Units::Metric))
+        );
+        assert_eq!("81", policy.percentage(0.81));
+    }
+}
@@ -0,0 +1,542 @@
+//! [`Arbitrary`] implementations for the models, for fuzzing and property testing.
+//!
+//! Values stay within ranges OWM actually reports: humidity and cloudiness are percentages,
+//! wind directions are degrees, times fall between 2000 and 2100, alerts end after they start,
+//! and weather conditions have a real id with its matching group and icon.
+
+use crate::{
+    Alert, Current, Daily, DailyFeelsLikeTemperature, DailyTemperature, Hourly, Main, Minutely,
+    Precipitation, Weather, WeatherElement,
+};
+use arbitrary::{Arbitrary, Result, Unstructured};
+use jiff::{tz::TimeZone, Timestamp, ToSpan, Zoned};
+
+/// A value in `min..=max`, rounded to two decimals as OWM sends them
+fn float(u: &mut Unstructured, min: f64, max: f64) -> Result<f64> {
+    let x = f64::from(u.int_in_range(0..=u32::MAX)?) / f64::from(u32::MAX);
+    Ok(round(min + (max - min) * x))
+}
+
+fn round(x: f64) -> f64 {
+    (x * 100.0).round() / 100.0
+}
+
+fn time(u: &mut Unstructured) -> Result<Zoned> {
+    // 2000-01-01 to 2100-01-01
+    let second = u.int_in_range(946_684_800..=4_102_444_800)?;
+    Ok(Timestamp::from_second(second)
+        .expect("within jiff's range")
+        .to_zoned(TimeZone::UTC))
+}
+
+/// `start` plus up to `max_hours`
+fn later(u: &mut Unstructured, start: &Zoned, max_hours: i64) -> Result<Zoned> {
+    let seconds = u.int_in_range(0..=max_hours * 3600)?;
+    Ok(start.saturating_add(seconds.seconds()))
+}
+
+fn option<T>(
+    u: &mut Unstructured,
+    f: impl FnOnce(&mut Unstructured) -> Result<T>,
+) -> Result<Option<T>> {
+    Ok(if u.arbitrary()? { Some(f(u)?) } else { None })
+}
+
+fn vec<'a, T: Arbitrary<'a>>(u: &mut Unstructured<'a>, max: usize) -> Result<Vec<T>> {
+    let len = u.int_in_range(0..=max)?;
+    (0..len).map(|_| T::arbitrary(u)).collect()
+}
+
+fn temperature(u: &mut Unstructured) -> Result<f64> {
+    float(u, -60.0, 50.0)
+}
+
+fn percent(u: &mut Unstructured) -> Result<u8> {
+    u.int_in_range(0..=100)
+}
+
+fn pressure(u: &mut Unstructured) -> Result<u16> {
+    u.int_in_range(870..=1085)
+}
+
+fn visibility(u: &mut Unstructured) -> Result<Option<u32>> {
+    option(u, |u| u.int_in_range(0..=10_000))
+}
+
+fn wind_speed(u: &mut Unstructured) -> Result<f64> {
+    float(u, 0.0, 60.0)
+}
+
+fn wind_gust(u: &mut Unstructured) -> Result<Option<f64>> {
+    option(u, wind_speed)
+}
+
+fn wind_deg(u: &mut Unstructured) -> Result<u16> {
+    u.int_in_range(0..=359)
+}
+
+fn uvi(u: &mut Unstructured) -> Result<Option<f64>> {
+    option(u, |u| float(u, 0.0, 15.0))
+}
+
+fn pop(u: &mut Unstructured) -> Result<Option<f64>> {
+    option(u, |u| float(u, 0.0, 1.0))
+}
+
+fn weather(u: &mut Unstructured) -> Result<Vec<WeatherElement>> {
+    (0..u.int_in_range(1..=2)?)
+        .map(|_| WeatherElement::arbitrary(u))
+        .collect()
+}
+
+/// Condition ids with their group, description, and icon (without the day or night suffix)
+const CONDITIONS: [(i64, Main, &str, &str); 22] = [
+    (
+        200,
+        Main::Thunderstorm,
+        "thunderstorm with light rain",
+        "11",
+    ),
+    (211, Main::Thunderstorm, "thunderstorm", "11"),
+    (300, Main::Drizzle, "light intensity drizzle", "09"),
+    (500, Main::Rain, "light rain", "10"),
+    (502, Main::Rain, "heavy intensity rain", "10"),
+    (511, Main::Rain, "freezing rain", "13"),
+    (521, Main::Rain, "shower rain", "09"),
+    (600, Main::Snow, "light snow", "13"),
+    (611, Main::Snow, "sleet", "13"),
+    (701, Main::Mist, "mist", "50"),
+    (711, Main::Smoke, "smoke", "50"),
+    (721, Main::Haze, "haze", "50"),
+    (731, Main::Dust, "sand/dust whirls", "50"),
+    (741, Main::Fog, "fog", "50"),
+    (751, Main::Sand, "sand", "50"),
+    (762, Main::Ash, "volcanic ash", "50"),
+    (771, Main::Squall, "squalls", "50"),
+    (781, Main::Tornado, "tornado", "50"),
+    (800, Main::Clear, "clear sky", "01"),
+    (801, Main::Clouds, "few clouds", "02"),
+    (802, Main::Clouds, "scattered clouds", "03"),
+    (804, Main::Clouds, "overcast clouds", "04"),
+];
+
+impl<'a> Arbitrary<'a> for WeatherElement {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let &(id, main, description, icon) = u.choose(&CONDITIONS)?;
+        let suffix = if u.arbitrary()? { 'd' } else { 'n' };
+        Ok(Self {
+            id,
+            main,
+            description: description.to_owned(),
+            icon: format!("{icon}{suffix}"),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Main {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(u.choose(&CONDITIONS)?.1)
+    }
+}
+
+impl<'a> Arbitrary<'a> for Precipitation {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            one_hour: option(u, |u| float(u, 0.0, 50.0))?,
+            three_hours: option(u, |u| float(u, 0.0, 100.0))?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Weather {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            lat: float(u, -90.0, 90.0)?,
+            lon: float(u, -180.0, 180.0)?,
+            timezone: String::arbitrary(u)?,
+            timezone_offset: u.int_in_range(-12 * 3600..=14 * 3600)?,
+            current: Option::arbitrary(u)?,
+            minutely: option(u, |u| vec(u, 61))?,
+            hourly: option(u, |u| vec(u, 48))?,
+            daily: option(u, |u| vec(u, 8))?,
+            alerts: option(u, |u| vec(u, 3))?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Current {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dt = time(u)?;
+        let sunrise = option(u, |u| later(u, &dt, 12))?;
+        let sunset = match &sunrise {
+            Some(sunrise) => option(u, |u| later(u, sunrise, 18))?,
+            None => None,
+        };
+        Ok(Self {
+            dt,
+            sunrise,
+            sunset,
+            temp: temperature(u)?,
+            feels_like: temperature(u)?,
+            pressure: pressure(u)?,
+            humidity: percent(u)?,
+            dew_point: temperature(u)?,
+            clouds: percent(u)?,
+            uvi: float(u, 0.0, 15.0)?,
+            visibility: visibility(u)?,
+            wind_speed: wind_speed(u)?,
+            wind_gust: wind_gust(u)?,
+            wind_deg: wind_deg(u)?,
+            rain: Option::arbitrary(u)?,
+            snow: Option::arbitrary(u)?,
+            weather: weather(u)?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Minutely {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            dt: time(u)?,
+            precipitation: float(u, 0.0, 50.0)?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Hourly {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            dt: time(u)?,
+            temp: temperature(u)?,
+            feels_like: temperature(u)?,
+            pressure: pressure(u)?,
+            humidity: percent(u)?,
+            dew_point: temperature(u)?,
+            uvi: uvi(u)?,
+            clouds: percent(u)?,
+            visibility: visibility(u)?,
+            wind_speed: wind_speed(u)?,
+            wind_gust: wind_gust(u)?,
+            wind_deg: wind_deg(u)?,
+            pop: pop(u)?,
+            rain: Option::arbitrary(u)?,
+            snow: Option::arbitrary(u)?,
+            weather: weather(u)?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Daily {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let dt = time(u)?;
+        let sunrise = option(u, |u| later(u, &dt, 12))?;
+        let sunset = match &sunrise {
+            Some(sunrise) => option(u, |u| later(u, sunrise, 18))?,
+            None => None,
+        };
+        let moonrise = later(u, &dt, 24)?;
+        let moonset = later(u, &moonrise, 24)?;
+        Ok(Self {
+            dt,
+            sunrise,
+            sunset,
+            moonrise,
+            moonset,
+            moon_phase: float(u, 0.0, 1.0)?,
+            summary: Option::arbitrary(u)?,
+            temp: DailyTemperature::arbitrary(u)?,
+            feels_like: DailyFeelsLikeTemperature::arbitrary(u)?,
+            pressure: pressure(u)?,
+            humidity: percent(u)?,
+            dew_point: temperature(u)?,
+            wind_speed: wind_speed(u)?,
+            wind_gust: wind_gust(u)?,
+            wind_deg: wind_deg(u)?,
+            clouds: percent(u)?,
+            uvi: float(u, 0.0, 15.0)?,
+            pop: pop(u)?,
+            rain: option(u, |u| float(u, 0.0, 200.0))?,
+            snow: option(u, |u| float(u, 0.0, 200.0))?,
+            weather: weather(u)?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DailyTemperature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (morn, day, eve, night) = (
+            temperature(u)?,
+            temperature(u)?,
+            temperature(u)?,
+            temperature(u)?,
+        );
+        let temps = [morn, day, eve, night];
+        let min = temps.into_iter().fold(f64::INFINITY, f64::min);
+        let max = temps.into_iter().fold(f64::NEG_INFINITY, f64::max);
+        Ok(Self {
+            morn,
+            day,
+            eve,
+            night,
+            min: round(min - float(u, 0.0, 5.0)?),
+            max: round(max + float(u, 0.0, 5.0)?),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for DailyFeelsLikeTemperature {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        Ok(Self {
+            morn: temperature(u)?,
+            day: temperature(u)?,
+            eve: temperature(u)?,
+            night: temperature(u)?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Alert {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let start = time(u)?;
+        let end = later(u, &start, 72)?;
+        Ok(Self {
+            sender_name: String::arbitrary(u)?,
+            event: String::arbitrary(u)?,
+            start,
+            end,
+            description: String::arbitrary(u)?,
+            tags: vec(u, 3)?,
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        })
+    }
+}
+
+#[cfg(feature = "current-weather")]
+mod current_weather {
+    use super::*;
+    use crate::current_weather::{Clouds, Coord, CurrentWeather, MainParameters, Sys, Wind};
+
+    impl<'a> Arbitrary<'a> for CurrentWeather {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self {
+                coord: Coord::arbitrary(u)?,
+                weather: weather(u)?,
+                base: Option::arbitrary(u)?,
+                main: MainParameters::arbitrary(u)?,
+                visibility: visibility(u)?,
+                wind: Wind::arbitrary(u)?,
+                clouds: Clouds::arbitrary(u)?,
+                rain: Option::arbitrary(u)?,
+                snow: Option::arbitrary(u)?,
+                dt: time(u)?,
+                sys: Sys::arbitrary(u)?,
+                timezone: u.int_in_range(-12 * 3600..=14 * 3600)?,
+                id: u.arbitrary()?,
+                name: String::arbitrary(u)?,
+                cod: 200,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Coord {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self {
+                lon: float(u, -180.0, 180.0)?,
+                lat: float(u, -90.0, 90.0)?,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for MainParameters {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let temp = temperature(u)?;
+            Ok(Self {
+                temp,
+                feels_like: temperature(u)?,
+                temp_min: round(temp - float(u, 0.0, 5.0)?),
+                temp_max: round(temp + float(u, 0.0, 5.0)?),
+                pressure: pressure(u)?,
+                humidity: percent(u)?,
+                sea_level: option(u, pressure)?,
+                grnd_level: option(u, pressure)?,
+                temp_kf: option(u, |u| float(u, -5.0, 5.0))?,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Wind {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self {
+                speed: wind_speed(u)?,
+                deg: wind_deg(u)?,
+                gust: wind_gust(u)?,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Clouds {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self {
+                all: percent(u)?,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for Sys {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let sunrise = time(u)?;
+            let sunset = later(u, &sunrise, 18)?;
+            Ok(Self {
+                kind: Option::arbitrary(u)?,
+                id: Option::arbitrary(u)?,
+                country: Option::arbitrary(u)?,
+                sunrise,
+                sunset,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+}
+
+#[cfg(feature = "forecast")]
+mod forecast {
+    use super::*;
+    use crate::{
+        current_weather::{Clouds, Coord, MainParameters, Wind},
+        forecast::{City, Forecast, ForecastStep, ForecastSys, PartOfDay},
+    };
+
+    impl<'a> Arbitrary<'a> for Forecast {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let list: Vec<ForecastStep> = vec(u, 40)?;
+            Ok(Self {
+                cod: "200".to_owned(),
+                message: 0.0,
+                cnt: list.len() as u8,
+                list,
+                city: City::arbitrary(u)?,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for ForecastStep {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let dt = time(u)?;
+            let dt_txt = dt.strftime("%Y-%m-%d %H:%M:%S").to_string();
+            Ok(Self {
+                dt,
+                main: MainParameters::arbitrary(u)?,
+                weather: weather(u)?,
+                clouds: Clouds::arbitrary(u)?,
+                wind: Wind::arbitrary(u)?,
+                visibility: visibility(u)?,
+                pop: float(u, 0.0, 1.0)?,
+                rain: Option::arbitrary(u)?,
+                snow: Option::arbitrary(u)?,
+                sys: ForecastSys::arbitrary(u)?,
+                dt_txt,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for ForecastSys {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(Self {
+                pod: PartOfDay::arbitrary(u)?,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for PartOfDay {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            Ok(if u.arbitrary()? {
+                Self::Day
+            } else {
+                Self::Night
+            })
+        }
+    }
+
+    impl<'a> Arbitrary<'a> for City {
+        fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+            let sunrise = time(u)?;
+            let sunset = later(u, &sunrise, 18)?;
+            Ok(Self {
+                id: u.arbitrary()?,
+                name: String::arbitrary(u)?,
+                coord: Coord::arbitrary(u)?,
+                country: String::arbitrary(u)?,
+                population: Option::arbitrary(u)?,
+                timezone: u.int_in_range(-12 * 3600..=14 * 3600)?,
+                sunrise,
+                sunset,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for seed in 1..20u32 {
+            let bytes: Vec<u8> = (0..8192u32)
+                .map(|x| (x * seed * 7919 % 251) as u8)
+                .collect();
+            let weather = Weather::arbitrary(&mut Unstructured::new(&bytes)).unwrap();
+
+            let json = serde_json::to_string(&weather).unwrap();
+            let parsed: Weather = serde_json::from_str(&json).unwrap();
+            assert_eq!(json, serde_json::to_string(&parsed).unwrap());
+
+            for hourly in parsed.hourly.iter().flatten() {
+                assert!(hourly.humidity <= 100);
+                assert!(hourly.wind_deg < 360);
+                assert_eq!(3, hourly.weather[0].icon.len());
+            }
+            for alert in parsed.alerts.iter().flatten() {
+                assert!(alert.start <= alert.end);
+            }
+        }
+    }
+}
@@ -0,0 +1,195 @@
+//! Export of sunrises, sunsets, and alerts to [iCalendar](https://www.rfc-editor.org/rfc/rfc5545),
+//! for subscribing to a forecast from calendar apps.
+//!
+//! [`to_ical`] writes a calendar with an event at each day's sunrise and sunset, and an event for
+//! each alert from its start to its end with a reminder when it starts. Event ids are derived
+//! from the location and the day or alert, so a calendar app refreshing the subscription updates
+//! the events instead of duplicating them.
+//!
+//! ```
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../fixtures/onecall.json"))?;
+//! let mut ics = Vec::new();
+//! owm_rs::ical::to_ical(&mut ics, &weather)?;
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{Alert, Weather};
+use jiff::{Timestamp, Zoned};
+use std::io;
+
+/// Longest line in octets, not counting the line break. Longer lines are folded.
+const LINE_LENGTH: usize = 75;
+
+/// Write the sunrises, sunsets, and alerts of `weather` as an iCalendar to `w`
+///
+/// Events are stamped with the time of the current conditions, or the time of writing if the
+/// response has none.
+pub fn to_ical<W: io::Write>(mut w: W, weather: &Weather) -> io::Result<()> {
+    let stamp = weather
+        .current
+        .as_ref()
+        .map_or_else(Timestamp::now, |x| x.dt.timestamp());
+    let location = format!("{:.4}/{:.4}", weather.lat, weather.lon);
+
+    write_line(&mut w, "BEGIN:VCALENDAR")?;
+    write_line(&mut w, "VERSION:2.0")?;
+    write_line(&mut w, "PRODID:-//owm-rs//EN")?;
+    write_line(&mut w, "CALSCALE:GREGORIAN")?;
+    write_line(&mut w, "METHOD:PUBLISH")?;
+    write_line(
+        &mut w,
+        &format!(
+            "X-WR-CALNAME:{}",
+            escape(&format!("Weather in {}", weather.timezone))
+        ),
+    )?;
+
+    let local = weather.time_zone();
+    for daily in weather.daily.iter().flatten() {
+        let day = daily.dt.with_time_zone(local.clone()).strftime("%Y%m%d");
+        let sun = [
+            ("sunrise", "Sunrise", &daily.sunrise),
+            ("sunset", "Sunset", &daily.sunset),
+        ];
+        for (kind, summary, at) in sun {
+            let Some(at) = at else {
+                continue;
+            };
+            write_line(&mut w, "BEGIN:VEVENT")?;
+            write_line(&mut w, &format!("UID:{kind}-{day}-{location}@owm-rs"))?;
+            write_line(&mut w, &format!("DTSTAMP:{}", utc(stamp)))?;
+            write_line(&mut w, &format!("DTSTART:{}", time(at)))?;
+            write_line(&mut w, &format!("SUMMARY:{summary}"))?;
+            write_line(&mut w, "TRANSP:TRANSPARENT")?;
+            write_line(&mut w, "END:VEVENT")?;
+        }
+    }
+
+    for alert in weather.alerts.iter().flatten() {
+        write_alert(&mut w, alert, stamp, &location)?;
+    }
+
+    write_line(&mut w, "END:VCALENDAR")?;
+    w.flush()
+}
+
+fn write_alert(
+    w: &mut impl io::Write,
+    alert: &Alert,
+    stamp: Timestamp,
+    location: &str,
+) -> io::Result<()> {
+    let id = format!(
+        "alert-{}-{}-{location}@owm-rs",
+        alert.start.timestamp().as_second(),
+        alert.event.to_lowercase().replace(' ', "-"),
+    );
+    write_line(w, "BEGIN:VEVENT")?;
+    write_line(w, &format!("UID:{}", escape(&id)))?;
+    write_line(w, &format!("DTSTAMP:{}", utc(stamp)))?;
+    write_line(w, &format!("DTSTART:{}", time(&alert.start)))?;
+    write_line(w, &format!("DTEND:{}", time(&alert.end)))?;
+    write_line(w, &format!("SUMMARY:{}", escape(&alert.event)))?;
+    write_line(w, &format!("DESCRIPTION:{}", escape(&alert.description)))?;
+    if !alert.tags.is_empty() {
+        let tags: Vec<String> = alert.tags.iter().map(|x| escape(x)).collect();
+        write_line(w, &format!("CATEGORIES:{}", tags.join(",")))?;
+    }
+    write_line(w, "BEGIN:VALARM")?;
+    write_line(w, "ACTION:DISPLAY")?;
+    write_line(w, &format!("DESCRIPTION:{}", escape(&alert.event)))?;
+    write_line(w, "TRIGGER:PT0S")?;
+    write_line(w, "END:VALARM")?;
+    write_line(w, "END:VEVENT")
+}
+
+fn time(dt: &Zoned) -> String {
+    utc(dt.timestamp())
+}
+
+fn utc(timestamp: Timestamp) -> String {
+    timestamp.strftime("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Escape a TEXT value
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Write a content line, folding it into lines of at most [`LINE_LENGTH`] octets without
+/// splitting a character
+fn write_line(w: &mut impl io::Write, line: &str) -> io::Result<()> {
+    let mut rest = line;
+    let mut limit = LINE_LENGTH;
+    while rest.len() > limit {
+        let mut end = limit;
+        while !rest.is_char_boundary(end) {
+            end -= 1;
+        }
+        w.write_all(&rest.as_bytes()[..end])?;
+        w.write_all(b"\r\n ")?;
+        rest = &rest[end..];
+        // The leading space of a continuation counts toward its length
+        limit = LINE_LENGTH - 1;
+    }
+    w.write_all(rest.as_bytes())?;
+    w.write_all(b"\r\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn calendar() {
+        let weather = crate::fixtures::full();
+        let mut ics = Vec::new();
+        to_ical(&mut ics, &weather).unwrap();
+        let ics = String::from_utf8(ics).unwrap();
+
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ics.ends_with("END:VCALENDAR\r\n"));
+        assert!(ics
+            .split("\r\n")
+            .all(|x| x.len() <= LINE_LENGTH && !x.contains('\n')));
+
+        let daily = weather.daily.as_ref().unwrap();
+        let alerts = weather.alerts.as_ref().unwrap();
+        let suns = daily
+            .iter()
+            .map(|x| usize::from(x.sunrise.is_some()) + usize::from(x.sunset.is_some()))
+            .sum::<usize>();
+        assert_eq!(suns + alerts.len(), ics.matches("BEGIN:VEVENT").count());
+        assert_eq!(alerts.len(), ics.matches("BEGIN:VALARM").count());
+
+        let sunrise = daily[0].sunrise.as_ref().unwrap();
+        assert!(ics.contains(&format!("DTSTART:{}\r\n", time(sunrise))));
+    }
+
+    #[test]
+    fn escape_and_fold() {
+        assert_eq!(r"a\, b\; c\\d\ne", escape("a, b; c\\d\r\ne"));
+
+        let mut folded = Vec::new();
+        write_line(&mut folded, &"é".repeat(40)).unwrap();
+        let folded = String::from_utf8(folded).unwrap();
+        let lines: Vec<&str> = folded.trim_end().split("\r\n").collect();
+        assert_eq!(
+            vec![74, 7],
+            lines.iter().map(|x| x.len()).collect::<Vec<_>>()
+        );
+        assert_eq!("é".repeat(40), lines.concat().replace(' ', ""));
+    }
+}
@@ -0,0 +1,143 @@
+//! Lenient deserialization for integer fields.
+//!
+//! Accepts any number and rounds it to the nearest integer, so a value such as a visibility of
+//! `10000.0` doesn't fail the whole response. Numbers in strings are accepted as
+//! [`lenient`](crate::lenient) describes. Values are never clamped: an impossible but
+//! representable value, such as a humidity of `104`, is kept for
+//! [`Weather::validate`](crate::Weather::validate) to report, and one outside the range of the
+//! field's type is an error.
+//!
+//! Formats that aren't human-readable, such as bincode, read the value back as the integer type
+//! it was serialized as.
+
+use serde::{de, de::DeserializeOwned, Deserializer};
+use std::fmt;
+
+pub(crate) trait Integer: DeserializeOwned + TryFrom<i64> {
+    const MIN: i128;
+    const MAX: i128;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {$(
+        impl Integer for $t {
+            const MIN: i128 = <$t>::MIN as i128;
+            const MAX: i128 = <$t>::MAX as i128;
+        }
+    )*};
+}
+
+impl_integer!(u8, u16, u32, u64, i32, i64);
+
+struct IntegerVisitor;
+
+impl de::Visitor<'_> for IntegerVisitor {
+    type Value = i64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an integer, or a number or string that rounds to one")
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<i64, E> {
+        Ok(value)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<i64, E> {
+        i64::try_from(value)
+            .map_err(|_| de::Error::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<i64, E> {
+        let rounded = value.round();
+        // i64::MAX isn't exactly representable, and rounds up to 2^63
+        if rounded >= i64::MIN as f64 && rounded < i64::MAX as f64 {
+            Ok(rounded as i64)
+        } else {
+            Err(de::Error::invalid_value(
+                de::Unexpected::Float(value),
+                &self,
+            ))
+        }
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<i64, E> {
+        let trimmed = value.trim();
+        if let Ok(value) = trimmed.parse::<i64>() {
+            return Ok(value);
+        }
+        match trimmed.parse::<f64>() {
+            Ok(x) => self.visit_f64(x),
+            Err(_) => Err(de::Error::invalid_value(de::Unexpected::Str(value), &self)),
+        }
+    }
+}
+
+pub(crate) fn deserialize<'de, D, T>(d: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Integer,
+{
+    if !d.is_human_readable() {
+        return T::deserialize(d);
+    }
+    let value = d.deserialize_any(IntegerVisitor)?;
+    T::try_from(value).map_err(|_| {
+        de::Error::invalid_value(
+            de::Unexpected::Signed(value),
+            &format!("an integer from {} to {}", T::MIN, T::MAX).as_str(),
+        )
+    })
+}
+
+pub(crate) mod option {
+    use super::Integer;
+    use serde::{Deserialize, Deserializer};
+
+    pub(crate) fn deserialize<'de, D, T>(d: D) -> Result<Option<T>, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: Integer,
+    {
+        #[derive(Deserialize)]
+        struct Wrapper<T: Integer>(#[serde(deserialize_with = "super::deserialize")] T);
+
+        Ok(Option::<Wrapper<T>>::deserialize(d)?.map(|x| x.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Sample {
+        #[serde(deserialize_with = "super::deserialize")]
+        humidity: u8,
+        #[serde(default, deserialize_with = "super::option::deserialize")]
+        visibility: Option<u32>,
+    }
+
+    fn parse(json: &str) -> Result<Sample, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    #[test]
+    fn round_without_clamping() {
+        let x = parse(r#"{"humidity":101.4,"visibility":70000.0}"#).unwrap();
+        assert_eq!(101, x.humidity);
+        assert_eq!(Some(70000), x.visibility);
+
+        let x = parse(r#"{"humidity":"250","visibility":null}"#).unwrap();
+        assert_eq!(250, x.humidity);
+        assert_eq!(None, x.visibility);
+
+        let e = parse(r#"{"humidity":-3}"#).unwrap_err();
+        assert!(
+            e.to_string()
+                .starts_with("invalid value: integer `-3`, expected an integer from 0 to 255"),
+            "{e}"
+        );
+        assert!(parse(r#"{"humidity":"300"}"#).is_err());
+        assert!(parse(r#"{"humidity":"NaN"}"#).is_err());
+    }
+}
@@ -0,0 +1,106 @@
+//! Values between hourly entries.
+//!
+//! Everything here is derived data: OWM doesn't forecast between hours, so these are estimates
+//! made by linearly interpolating the surrounding entries.
+
+use crate::{Hourly, WeatherElement};
+use jiff::Zoned;
+
+/// Conditions at an arbitrary time, interpolated from the surrounding hourly entries by
+/// [`Weather::weather_at`](crate::Weather::weather_at). Values are in the units of the request.
+#[derive(Debug, Clone)]
+pub struct Interpolated {
+    pub dt: Zoned,
+    pub temp: f64,
+    pub feels_like: f64,
+    pub pressure: u16,
+    pub humidity: u8,
+    pub dew_point: f64,
+    pub clouds: u8,
+    /// Interpolated if both surrounding entries have one, otherwise the nearest entry's
+    pub uvi: Option<f64>,
+    pub wind_speed: f64,
+
+    /// Interpolated if both surrounding entries have a gust, otherwise the nearest entry's
+    pub wind_gust: Option<f64>,
+
+    /// Interpolated along the shorter way around the compass
+    pub wind_deg: u16,
+
+    /// Conditions of the nearest entry
+    pub weather: Vec<WeatherElement>,
+}
+
+pub(crate) fn weather_at(hourly: &[Hourly], time: &Zoned) -> Option<Interpolated> {
+    let after = hourly.iter().position(|x| x.dt >= *time)?;
+    let b = &hourly[after];
+    let a = if b.dt == *time {
+        b
+    } else {
+        &hourly[after.checked_sub(1)?]
+    };
+
+    let seconds = |x: &Zoned| x.timestamp().as_second() as f64;
+    let span = seconds(&b.dt) - seconds(&a.dt);
+    let t = if span > 0.0 {
+        (seconds(time) - seconds(&a.dt)) / span
+    } else {
+        0.0
+    };
+    let lerp = |x: f64, y: f64| x + (y - x) * t;
+    let nearest = if t < 0.5 { a } else { b };
+
+    let turn = (f64::from(b.wind_deg) - f64::from(a.wind_deg) + 180.0).rem_euclid(360.0) - 180.0;
+    let wind_deg = (f64::from(a.wind_deg) + turn * t).rem_euclid(360.0).round() as u16 % 360;
+
+    Some(Interpolated {
+        dt: time.clone(),
+        temp: lerp(a.temp, b.temp),
+        feels_like: lerp(a.feels_like, b.feels_like),
+        pressure: lerp(a.pressure.into(), b.pressure.into()).round() as u16,
+        humidity: lerp(a.humidity.into(), b.humidity.into()).round() as u8,
+        dew_point: lerp(a.dew_point, b.dew_point),
+        clouds: lerp(a.clouds.into(), b.clouds.into()).round() as u8,
+        uvi: match (a.uvi, b.uvi) {
+            (Some(x), Some(y)) => Some(lerp(x, y)),
+            _ => nearest.uvi,
+        },
+        wind_speed: lerp(a.wind_speed, b.wind_speed),
+        wind_gust: match (a.wind_gust, b.wind_gust) {
+            (Some(x), Some(y)) => Some(lerp(x, y)),
+            _ => nearest.wind_gust,
+        },
+        wind_deg,
+        weather: nearest.weather.clone(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use jiff::Span;
+
+    #[test]
+    fn interpolate() {
+        let mut weather = crate::fixtures::full();
+        let hourly = weather.hourly.as_mut().unwrap();
+        hourly[0].wind_deg = 350;
+        hourly[1].wind_deg = 30;
+        let hourly = weather.hourly.as_ref().unwrap();
+
+        let time = &hourly[0].dt + Span::new().minutes(15);
+        let x = weather.weather_at(&time).unwrap();
+        assert!((x.temp - (hourly[0].temp + (hourly[1].temp - hourly[0].temp) / 4.0)).abs() < 1e-9);
+        assert_eq!(0, x.wind_deg);
+        assert_eq!(hourly[0].weather[0].id, x.weather[0].id);
+
+        let exact = weather.weather_at(&hourly[2].dt).unwrap();
+        assert_eq!(hourly[2].temp, exact.temp);
+
+        assert!(weather
+            .weather_at(&(&hourly[0].dt - Span::new().minutes(1)))
+            .is_none());
+        assert!(weather
+            .weather_at(&(&hourly[5].dt + Span::new().minutes(1)))
+            .is_none());
+    }
+}
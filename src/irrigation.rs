@@ -0,0 +1,124 @@
+//! Water/don't water recommendations for smart sprinkler integrations.
+//!
+//! Keeps a simple daily soil water balance: reference evapotranspiration (see
+//! [`derive::reference_evapotranspiration`]) scaled by a crop coefficient dries the soil out,
+//! and precipitation wets it again. Past days count their full precipitation volume, forecasted
+//! days count their volume weighted by the probability of precipitation, or in full if it's
+//! missing. Watering is recommended once the deficit passes the allowable amount, and is assumed
+//! to refill the soil.
+
+use crate::{aviation, derive, Daily, Units};
+use jiff::Zoned;
+
+/// Site and plant parameters for [`IrrigationPlanner::plan`]
+#[derive(Debug, Clone, Copy)]
+pub struct IrrigationPlanner {
+    /// Units the daily entries were requested in
+    pub units: Units,
+
+    /// Latitude of the site, degrees
+    pub latitude: f64,
+
+    /// Elevation of the site, metres
+    pub elevation: f64,
+
+    /// Ratio of the plant's water use to the reference grass. 1.0 is a good default for lawns in
+    /// the growing season.
+    pub crop_coefficient: f64,
+
+    /// Water deficit the plants tolerate before needing water, mm
+    pub allowable_deficit: f64,
+
+    /// Water deficit at the start of the first day, mm
+    pub initial_deficit: f64,
+}
+
+/// Recommendation for a single day
+#[derive(Debug)]
+pub struct IrrigationDay {
+    /// Time of the day
+    pub dt: Zoned,
+
+    /// Water lost to evapotranspiration, mm
+    pub evapotranspiration: f64,
+
+    /// Precipitation counted towards the water balance, mm
+    pub precipitation: f64,
+
+    /// Water deficit at the end of the day, before watering, mm
+    pub deficit: f64,
+
+    /// Whether to water at the end of the day
+    pub water: bool,
+}
+
+impl IrrigationPlanner {
+    /// Recommend for each of `history` (days that already happened) and `forecast`, which are
+    /// expected to be consecutive and in order
+    pub fn plan(&self, history: &[Daily], forecast: &[Daily]) -> Vec<IrrigationDay> {
+        let past = history.iter().map(|day| (day, 1.0));
+        let upcoming = forecast.iter().map(|day| (day, day.pop.unwrap_or(1.0)));
+
+        let mut deficit = self.initial_deficit;
+        past.chain(upcoming)
+            .map(|(day, weight)| {
+                let evapotranspiration = self.crop_coefficient * self.evapotranspiration(day);
+                let precipitation = weight * day.total_precipitation().millimetres();
+
+                deficit = (deficit + evapotranspiration - precipitation).max(0.0);
+                let water = deficit > self.allowable_deficit;
+                let result = IrrigationDay {
+                    dt: day.dt.clone(),
+                    evapotranspiration,
+                    precipitation,
+                    deficit,
+                    water,
+                };
+                if water {
+                    deficit = 0.0;
+                }
+                result
+            })
+            .collect()
+    }
+
+    fn evapotranspiration(&self, day: &Daily) -> f64 {
+        derive::reference_evapotranspiration(
+            self.units.to_celsius(day.temp.min),
+            self.units.to_celsius(day.temp.max),
+            self.units.to_celsius(day.dew_point),
+            self.units.to_metres_per_second(day.wind_speed),
+            aviation::station_pressure(f64::from(day.pressure), self.elevation),
+            self.latitude,
+            day.dt.day_of_year(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_watering() {
+        let weather = crate::fixtures::full();
+        let daily = weather.daily.unwrap();
+        let planner = IrrigationPlanner {
+            units: Units::Metric,
+            latitude: 49.9,
+            elevation: 239.0,
+            crop_coefficient: 1.0,
+            allowable_deficit: 20.0,
+            initial_deficit: 15.0,
+        };
+
+        let plan = planner.plan(&daily[..1], &daily[1..]);
+        assert_eq!(3, plan.len());
+        assert_eq!(3.44, plan[0].precipitation);
+        assert!((plan[2].precipitation - 0.74 * 1.87).abs() < 1e-9);
+        assert_eq!(
+            vec![false, true, false],
+            plan.iter().map(|x| x.water).collect::<Vec<_>>()
+        );
+    }
+}
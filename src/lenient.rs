@@ -0,0 +1,183 @@
+//! Lenient deserialization for number fields.
+//!
+//! Proxies and caches sometimes turn numbers into strings. These accept either a JSON number or
+//! a string holding one, such as `"21.5"`.
+//!
+//! Rain and snow are objects such as `{"1h": 0.5}` on current and hourly entries, but plain
+//! numbers on daily entries, and archived payloads sometimes mix the two up. [`precipitation`]
+//! and [`amount`] accept either shape.
+//!
+//! Only human-readable formats get this leniency. Compact binary formats such as bincode aren't
+//! self-describing, so values are read back as the types they were serialized as.
+
+use crate::Precipitation;
+use serde::{de, Deserialize, Deserializer};
+use std::fmt;
+
+struct NumberVisitor;
+
+impl de::Visitor<'_> for NumberVisitor {
+    type Value = f64;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a number, or a string containing one")
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<f64, E> {
+        Ok(value)
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<f64, E> {
+        Ok(value as f64)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<f64, E> {
+        Ok(value as f64)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<f64, E> {
+        value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|x| x.is_finite())
+            .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+pub(crate) fn deserialize<'de, D>(d: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    if d.is_human_readable() {
+        d.deserialize_any(NumberVisitor)
+    } else {
+        d.deserialize_f64(NumberVisitor)
+    }
+}
+
+pub(crate) mod option {
+    use serde::{Deserialize, Deserializer};
+
+    pub(crate) fn deserialize<'de, D>(d: D) -> Result<Option<f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(deserialize_with = "super::deserialize")] f64);
+
+        Ok(Option::<Wrapper>::deserialize(d)?.map(|x| x.0))
+    }
+}
+
+/// Rain or snow in either shape
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Shape {
+    Amount(#[serde(deserialize_with = "deserialize")] f64),
+    Volumes(Precipitation),
+}
+
+/// Rain or snow as a [`Precipitation`], treating a plain number as the volume for the last hour
+pub(crate) mod precipitation {
+    use super::Shape;
+    use crate::Precipitation;
+    use serde::{Deserialize, Deserializer};
+
+    pub(crate) fn deserialize<'de, D>(d: D) -> Result<Option<Precipitation>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !d.is_human_readable() {
+            return Option::deserialize(d);
+        }
+        Ok(Option::<Shape>::deserialize(d)?.map(|x| match x {
+            Shape::Amount(x) => Precipitation::from_amount(x, false),
+            Shape::Volumes(x) => x,
+        }))
+    }
+
+    /// Like [`deserialize`], but a plain number is the volume for the last 3 hours
+    #[cfg(feature = "forecast")]
+    pub(crate) fn three_hours<'de, D>(d: D) -> Result<Option<Precipitation>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !d.is_human_readable() {
+            return Option::deserialize(d);
+        }
+        Ok(Option::<Shape>::deserialize(d)?.map(|x| match x {
+            Shape::Amount(x) => Precipitation::from_amount(x, true),
+            Shape::Volumes(x) => x,
+        }))
+    }
+}
+
+/// Rain or snow as a plain amount, mm, taking the amount of an object
+pub(crate) mod amount {
+    use super::Shape;
+    use serde::{Deserialize, Deserializer};
+
+    pub(crate) fn deserialize<'de, D>(d: D) -> Result<Option<f64>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if !d.is_human_readable() {
+            return Option::deserialize(d);
+        }
+        Ok(Option::<Shape>::deserialize(d)?.map(|x| match x {
+            Shape::Amount(x) => x,
+            // A daily field holds the total for the day, whichever period the object names
+            Shape::Volumes(x) => x.one_hour.or(x.three_hours).unwrap_or_default(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Sample {
+        #[serde(deserialize_with = "super::deserialize")]
+        temp: f64,
+        #[serde(default, deserialize_with = "super::option::deserialize")]
+        wind_gust: Option<f64>,
+    }
+
+    #[test]
+    fn numbers_or_strings() {
+        let x: Sample = serde_json::from_str(r#"{"temp":21,"wind_gust":" 4.5"}"#).unwrap();
+        assert_eq!(21.0, x.temp);
+        assert_eq!(Some(4.5), x.wind_gust);
+
+        let x: Sample = serde_json::from_str(r#"{"temp":"-3.25"}"#).unwrap();
+        assert_eq!(-3.25, x.temp);
+        assert_eq!(None, x.wind_gust);
+
+        assert!(serde_json::from_str::<Sample>(r#"{"temp":"warm"}"#).is_err());
+        assert!(serde_json::from_str::<Sample>(r#"{"temp":"NaN"}"#).is_err());
+    }
+
+    #[test]
+    fn precipitation_shapes() {
+        #[derive(Deserialize)]
+        struct Entry {
+            #[serde(default, deserialize_with = "super::precipitation::deserialize")]
+            rain: Option<crate::Precipitation>,
+            #[serde(default, deserialize_with = "super::amount::deserialize")]
+            snow: Option<f64>,
+        }
+
+        let x: Entry = serde_json::from_str(r#"{"rain":0.8,"snow":{"1h":0.3}}"#).unwrap();
+        assert_eq!(0.8, x.rain.unwrap().amount_mm());
+        assert_eq!(Some(0.3), x.snow);
+
+        let x: Entry = serde_json::from_str(r#"{"rain":{"3h":1.5},"snow":{"3h":2}}"#).unwrap();
+        assert_eq!(0.5, x.rain.unwrap().amount_mm());
+        assert_eq!(Some(2.0), x.snow);
+
+        let x: Entry = serde_json::from_str("{}").unwrap();
+        assert!(x.rain.is_none() && x.snow.is_none());
+    }
+}
@@ -1,22 +1,181 @@
 //! Strongly typed models for OpenWeatherMap's "One Call" API:
 //! <https://openweathermap.org/api/one-call-3>
-
-use jiff::Zoned;
+//!
+//! Models for other endpoints are behind cargo features, all enabled by default:
+//!
+//! - `current-weather`: [current weather data](https://openweathermap.org/current)
+//! - `forecast`: [5 day / 3 hour forecast](https://openweathermap.org/forecast5)
+//! - `maps`: tile URLs of [weather maps](https://openweathermap.org/api/weathermaps)
+//!
+//! The `client` feature adds an async [`client`] for the One Call API, built on
+//! [reqwest](https://docs.rs/reqwest) and [tokio](https://docs.rs/tokio). It applies the crate's
+//! rate limiting, quota tracking, retries, caching, and conditional requests to each call, and
+//! can poll a location as a stream.
+//!
+//! The `config` feature adds loading an API key and defaults from a TOML file, and the
+//! `disk-cache` feature adds a cache that keeps responses in files.
+//!
+//! The `arrow` feature converts hourly and daily forecasts to Arrow record batches, with the
+//! same columns as the CSV [`export`].
+//!
+//! The `polars` feature adds `Weather::hourly_df` and `Weather::daily_df`, which return
+//! Polars data frames with the same columns.
+//!
+//! The `ical` feature exports each day's sunrise and sunset and the weather alerts as an
+//! iCalendar that calendar apps can subscribe to.
+//!
+//! The `prometheus` feature adds a [Prometheus](https://docs.rs/prometheus) collector with gauges
+//! of the latest conditions at each location.
+//!
+//! The `term` feature adds sparklines and bar charts of hourly forecasts for terminals.
+//!
+//! The `ratatui` feature adds [ratatui](https://docs.rs/ratatui) widgets for an hourly
+//! temperature chart, a daily forecast table, and an alert banner.
+//!
+//! The `arbitrary` feature implements [`Arbitrary`](https://docs.rs/arbitrary) for the models,
+//! with values in the ranges OWM reports, for fuzzing and property testing.
+//!
+//! The `schemars` feature derives [`JsonSchema`](https://docs.rs/schemars) for the models, with
+//! times described as the unix timestamps they're sent and serialized as.
+//!
+//! The `simd-json` feature adds `Weather::from_simd_slice` for parsing large batches of
+//! responses faster.
+//!
+//! Times are [`jiff`] values throughout. The `chrono` and `time` features add conversions to
+//! [`chrono`](https://docs.rs/chrono) and [`time`](https://docs.rs/time) types for use at the
+//! boundary with code built on those crates.
+//!
+//! The `tracing` feature logs client calls, cache lookups, retries, and rate limiting as
+//! [`tracing`](https://docs.rs/tracing) spans and events. Coordinates are only logged rounded to
+//! the precision of their cache key.
+//!
+//! For testing code that uses this crate, the `builders` feature adds builders for fabricating
+//! models, and the `fixtures` feature adds sample responses. The `strict` feature rejects
+//! responses with fields the models don't know about, so tests notice when OWM adds one.
+//!
+//! The `extra-fields` feature instead keeps those fields in an `extra` map on each model. It
+//! takes precedence over `strict` when both are enabled.
+//!
+//! Integer fields accept any JSON number, rounded to the nearest integer. Their types are wide
+//! enough for any value OWM reports: `u8` for humidity and cloudiness, `u16` for pressure and
+//! wind direction, and `u32` for visibility. Values aren't clamped, so impossible ones such as a
+//! humidity of 104% are kept for `Weather::validate` to report, and ones that don't fit the type
+//! fail the response. Weather condition ids stay `i64`, since new ones are added over time.
+//! Integer and decimal fields alike also accept numbers sent as strings, such as `"21.5"`.
+//!
+//! Models also serialize to compact binary formats such as bincode, CBOR, and MessagePack and
+//! read back the same, for caching responses. The leniency above only applies to human-readable
+//! formats. This doesn't work with `extra-fields`, since its map needs a self-describing format.
+
+use jiff::{civil::Date, tz::TimeZone, Span, Zoned};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
+pub mod aggregate;
+pub mod alerts;
+pub mod analysis;
+pub mod api_key;
+#[cfg(feature = "arrow")]
+pub mod arrow;
+pub mod aviation;
+#[cfg(feature = "builders")]
+pub mod builders;
+pub mod cache;
+#[cfg(feature = "chrono")]
+pub mod chrono_compat;
+#[cfg(feature = "client")]
+pub mod client;
+pub mod compare;
+pub mod conditional;
+#[cfg(feature = "config")]
+pub mod config;
+#[cfg(feature = "current-weather")]
+pub mod current_weather;
+#[cfg(feature = "polars")]
+pub mod dataframe;
+pub mod debug;
+pub mod derive;
+pub mod diff;
+#[cfg(feature = "disk-cache")]
+pub mod disk_cache;
+pub mod display;
+pub mod emoji;
+pub mod error;
+pub mod export;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+#[cfg(feature = "forecast")]
+pub mod forecast;
+pub mod format;
+#[cfg(feature = "ical")]
+pub mod ical;
+pub mod interpolate;
+pub mod irrigation;
+#[cfg(feature = "maps")]
+pub mod maps;
+#[cfg(feature = "prometheus")]
+pub mod metrics;
+pub mod minutely;
+pub mod moon;
+pub mod ordered;
+pub mod projection;
+pub mod quota;
+pub mod rate_limit;
+pub mod refresh;
+pub mod retry;
+pub mod sample;
+pub mod sms;
+pub mod supplement;
+#[cfg(feature = "term")]
+pub mod term;
+#[cfg(feature = "time")]
+pub mod time_compat;
+#[cfg(feature = "ratatui")]
+pub mod tui;
+pub mod units;
+pub mod uv;
+pub mod v25;
+pub mod validate;
+pub mod wind;
+
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+mod integer;
+mod lenient;
+
+pub use api_key::ApiKey;
+pub use error::Error;
+pub use units::Units;
+
+/// Unix timestamps in seconds
+///
+/// Timestamps are serialized as an `i64` and deserialized with the same hint, so formats that
+/// aren't self-describing, such as bincode, read back what was written. Human-readable formats
+/// also accept floats and numeric strings, read as [`integer`] describes.
 mod ts_seconds {
     use jiff::{tz::TimeZone, Timestamp, Zoned};
-    use serde::de;
+    use serde::{de, ser};
     use std::fmt;
 
     struct SecondsTimestampVisitor;
 
+    pub fn serialize<S>(dt: &Zoned, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        s.serialize_i64(dt.timestamp().as_second())
+    }
+
     pub fn deserialize<'de, D>(d: D) -> Result<Zoned, D::Error>
     where
         D: de::Deserializer<'de>,
     {
-        d.deserialize_i64(SecondsTimestampVisitor)
+        if d.is_human_readable() {
+            let seconds = crate::integer::deserialize::<_, i64>(d)?;
+            de::Visitor::visit_i64(SecondsTimestampVisitor, seconds)
+        } else {
+            d.deserialize_i64(SecondsTimestampVisitor)
+        }
     }
 
     impl<'de> de::Visitor<'de> for SecondsTimestampVisitor {
@@ -58,19 +217,103 @@ mod ts_seconds {
     }
 }
 
+/// Unix timestamps that may be missing, `null`, or `0`, as OWM reports sunrise and sunset during
+/// polar day and night
+mod ts_seconds_option {
+    use jiff::Zoned;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    pub fn serialize<S>(dt: &Option<Zoned>, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        dt.as_ref().map(|x| x.timestamp().as_second()).serialize(s)
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<Option<Zoned>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super::ts_seconds")] Zoned);
+
+        let dt = Option::<Wrapper>::deserialize(d)?.map(|x| x.0);
+        Ok(dt.filter(|x| x.timestamp().as_second() != 0))
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub struct OwmError {
     #[serde(rename = "cod")]
     pub code: ErrorCode,
     pub message: String,
+
+    /// (where available) Names of the request parameters that caused the error
+    pub parameters: Option<Vec<String>>,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(untagged)]
 pub enum ErrorCode {
     String(String),
     Number(i32),
 }
 
+/// Broad classification of an [`OwmError`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// Missing, invalid, or not yet activated API key (401)
+    InvalidApiKey,
+
+    /// No data for the requested location or resource (404)
+    NotFound,
+
+    /// Call limit of the subscription exceeded (429)
+    QuotaExceeded,
+
+    /// The API key's subscription doesn't include the endpoint, such as One Call 3.0 (401 or 403)
+    SubscriptionRequired,
+
+    /// Missing or invalid request parameters (400)
+    BadRequest,
+
+    /// Error on OWM's side (5xx)
+    ServerError,
+
+    Other,
+}
+
+impl ErrorCode {
+    /// The code as a number, if it is one. OWM sometimes sends it as a string.
+    pub fn as_number(&self) -> Option<i32> {
+        match self {
+            Self::String(s) => s.trim().parse().ok(),
+            Self::Number(n) => Some(*n),
+        }
+    }
+}
+
+impl OwmError {
+    /// Classify the error by its code, falling back to its message
+    pub fn kind(&self) -> ErrorKind {
+        match self.code.as_number() {
+            Some(401 | 403) if self.message.contains("subscription") => {
+                ErrorKind::SubscriptionRequired
+            }
+            Some(403) => ErrorKind::SubscriptionRequired,
+            Some(401) => ErrorKind::InvalidApiKey,
+            Some(404) => ErrorKind::NotFound,
+            Some(429) => ErrorKind::QuotaExceeded,
+            Some(400) => ErrorKind::BadRequest,
+            Some(500..=599) => ErrorKind::ServerError,
+            _ if self.message.contains("Invalid API key") => ErrorKind::InvalidApiKey,
+            _ => ErrorKind::Other,
+        }
+    }
+}
+
 impl fmt::Display for ErrorCode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -88,77 +331,485 @@ impl fmt::Display for OwmError {
 
 impl std::error::Error for OwmError {}
 
-#[derive(Debug, Deserialize)]
+/// Parse a response body, such as a [`Weather`], recognizing the error bodies OWM sends in place
+/// of one as an [`Error`] of their kind rather than a complaint about missing fields
+pub fn parse_response<T: serde::de::DeserializeOwned>(json: &str) -> Result<T, Error> {
+    // Every field of a response can be missing, so an error body would parse as an empty one
+    if let Ok(error) = serde_json::from_str::<OwmError>(json) {
+        return Err(Error::from(error));
+    }
+    serde_json::from_str(json).map_err(|e| {
+        #[cfg(feature = "tracing")]
+        tracing::debug!(error = %e, "couldn't parse response");
+        Error::deserialize(e, json)
+    })
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Weather {
+    /// Latitude of the location, decimal (−90; 90). `0` if the response doesn't have it, such
+    /// as one serialized by an older version of this crate.
+    #[serde(default, deserialize_with = "lenient::deserialize")]
+    pub lat: f64,
+
+    /// Longitude of the location, decimal (-180; 180). `0` if the response doesn't have it.
+    #[serde(default, deserialize_with = "lenient::deserialize")]
+    pub lon: f64,
+
+    /// Timezone name for the requested location. Empty if the response doesn't have it.
+    #[serde(default)]
+    pub timezone: String,
+
+    /// Shift in seconds from UTC. `0` if the response doesn't have it.
+    #[serde(default, deserialize_with = "integer::deserialize")]
+    pub timezone_offset: i32,
+
     pub current: Option<Current>,
     pub minutely: Option<Vec<Minutely>>,
     pub hourly: Option<Vec<Hourly>>,
     pub daily: Option<Vec<Daily>>,
     pub alerts: Option<Vec<Alert>>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Weather {
+    /// Parse a response with [`simd-json`](https://docs.rs/simd-json), which is faster than
+    /// `serde_json` on large batches of responses
+    ///
+    /// simd-json parses in place, so `json` is left scrambled afterwards.
+    #[cfg(feature = "simd-json")]
+    pub fn from_simd_slice(json: &mut [u8]) -> Result<Self, simd_json::Error> {
+        simd_json::serde::from_slice(json)
+    }
+
+    /// The hourly forecasts as a data frame, with the columns described in [`dataframe`]
+    #[cfg(feature = "polars")]
+    pub fn hourly_df(&self) -> polars::prelude::PolarsResult<polars::prelude::DataFrame> {
+        dataframe::hourly(self.hourly.as_deref().unwrap_or_default())
+    }
+
+    /// The daily forecasts as a data frame, with the columns described in [`dataframe`]
+    #[cfg(feature = "polars")]
+    pub fn daily_df(&self) -> polars::prelude::PolarsResult<polars::prelude::DataFrame> {
+        dataframe::daily(self.daily.as_deref().unwrap_or_default())
+    }
+
+    /// This response as a [GeoJSON](https://geojson.org) Feature, for map layers
+    ///
+    /// The geometry is a Point at the location, and the properties are the `timezone`, the
+    /// `current` conditions (`null` if excluded), and the `alerts` (empty without alerts), in
+    /// the same form as the response.
+    pub fn to_geojson(&self) -> serde_json::Value {
+        serde_json::json!({
+            "type": "Feature",
+            "geometry": {
+                "type": "Point",
+                "coordinates": [self.lon, self.lat],
+            },
+            "properties": {
+                "timezone": self.timezone,
+                "current": self.current,
+                "alerts": self.alerts.as_deref().unwrap_or_default(),
+            },
+        })
+    }
+
+    /// Convert temperatures and wind speeds from the `units` this response was requested in to
+    /// metric units
+    ///
+    /// Visibility and precipitation are always reported in metres and millimetres, so they're
+    /// left as is.
+    pub fn to_metric(self, units: Units) -> Self {
+        self.convert(units, Units::Metric)
+    }
+
+    /// Convert temperatures and wind speeds from the `units` this response was requested in to
+    /// imperial units
+    ///
+    /// Visibility and precipitation are always reported in metres and millimetres, so they're
+    /// left as is.
+    pub fn to_imperial(self, units: Units) -> Self {
+        self.convert(units, Units::Imperial)
+    }
+
+    /// Convert temperatures and wind speeds from the `units` this response was requested in to
+    /// standard units
+    ///
+    /// Visibility and precipitation are always reported in metres and millimetres, so they're
+    /// left as is.
+    pub fn to_standard(self, units: Units) -> Self {
+        self.convert(units, Units::Standard)
+    }
+
+    fn convert(mut self, from: Units, to: Units) -> Self {
+        units::Converter { from, to }.weather(&mut self);
+        self
+    }
+
+    /// Alerts in effect at `time`
+    pub fn alerts_active_at<'a>(&'a self, time: &'a Zoned) -> impl Iterator<Item = &'a Alert> {
+        self.alerts
+            .iter()
+            .flatten()
+            .filter(|x| x.is_active_at(time))
+    }
+
+    /// Alerts in effect at any point from `start` until `end`
+    pub fn alerts_overlapping<'a>(
+        &'a self,
+        start: &'a Zoned,
+        end: &'a Zoned,
+    ) -> impl Iterator<Item = &'a Alert> {
+        self.alerts
+            .iter()
+            .flatten()
+            .filter(|x| x.overlaps(start, end))
+    }
+
+    /// Hourly conditions and alerts for an event from `start` until `end`
+    pub fn for_event(&self, start: &Zoned, end: &Zoned) -> aggregate::EventForecast<'_> {
+        aggregate::EventForecast::new(self, start, end)
+    }
+
+    /// Expected rain and snow from the time of this response (the current entry's `dt`, or else
+    /// the first forecasted entry's) until `span` later
+    ///
+    /// Minutely intensities are used where available, followed by hourly volumes. Hours only
+    /// partly in the window count proportionally.
+    pub fn precipitation_next(&self, span: Span) -> units::Precipitation {
+        aggregate::precipitation_next(self, span)
+    }
+
+    /// Impossible values, such as a humidity above 100% or a sunset before sunrise, and series
+    /// that aren't in chronological order
+    pub fn validate(&self) -> Vec<validate::ValidationIssue> {
+        validate::validate(self)
+    }
+
+    /// Combine this response with a `newer` one for the same location into a continuous
+    /// timeline
+    ///
+    /// Series are unioned and sorted, with entries from `newer` replacing ones at the same time.
+    /// Alerts are concatenated, keeping one copy of alerts that appear in both.
+    pub fn merge(self, newer: Weather) -> Weather {
+        ordered::merge(self, newer)
+    }
+
+    /// Changes from this response to a `newer` one for the same location: new and removed
+    /// alerts, and changed temperatures and conditions of the hours in both
+    pub fn diff<'a>(&'a self, newer: &'a Weather) -> diff::Changeset<'a> {
+        diff::diff(self, newer)
+    }
+
+    /// Sort the minutely, hourly, and daily series by `dt`, keeping the last of any entries with
+    /// the same time, and sort alerts by start time
+    pub fn sort_series(&mut self) {
+        ordered::sort_series(self);
+    }
+
+    /// Offset from UTC of the requested location, at the time of the request
+    pub fn offset(&self) -> jiff::tz::Offset {
+        jiff::tz::Offset::from_seconds(self.timezone_offset).unwrap_or(jiff::tz::Offset::UTC)
+    }
+
+    /// Time zone of the requested location
+    ///
+    /// This is the IANA time zone named by [`timezone`](Self::timezone), so local dates follow
+    /// daylight saving time changes within the forecast. If the system's time zone database
+    /// doesn't have it, it's the fixed [`offset`](Self::offset) at the time of the request.
+    pub fn time_zone(&self) -> TimeZone {
+        TimeZone::get(&self.timezone).unwrap_or_else(|_| TimeZone::fixed(self.offset()))
+    }
+
+    /// Hourly entries starting from `start` until `end`
+    pub fn hours_between<'a>(
+        &'a self,
+        start: &'a Zoned,
+        end: &'a Zoned,
+    ) -> impl Iterator<Item = &'a Hourly> {
+        self.hourly
+            .iter()
+            .flatten()
+            .filter(|x| *start <= x.dt && x.dt < *end)
+    }
+
+    /// Daily entries from `start` to `end` inclusive, as dates at the requested location
+    pub fn days_between(&self, start: Date, end: Date) -> impl Iterator<Item = &Daily> {
+        let tz = self.time_zone();
+        self.daily.iter().flatten().filter(move |x| {
+            let date = x.dt.with_time_zone(tz.clone()).date();
+            start <= date && date <= end
+        })
+    }
+
+    /// Hourly entry covering `time`
+    pub fn hour_at(&self, time: &Zoned) -> Option<&Hourly> {
+        self.hourly
+            .iter()
+            .flatten()
+            .find(|x| x.dt <= *time && *time < x.dt.saturating_add(Span::new().hours(1)))
+    }
+
+    /// Conditions at `time`, linearly interpolated from the surrounding hourly entries
+    ///
+    /// This is derived data, not a forecast from OWM. Returns `None` outside of the hourly
+    /// series.
+    pub fn weather_at(&self, time: &Zoned) -> Option<interpolate::Interpolated> {
+        interpolate::weather_at(self.hourly.as_deref()?, time)
+    }
+
+    /// Daily entry for `date` at the requested location
+    pub fn day_at(&self, date: Date) -> Option<&Daily> {
+        self.days_between(date, date).next()
+    }
+
+    /// A slimmed down view of this response with only the given `fields`
+    pub fn project(&self, fields: &[projection::Field]) -> projection::Projection {
+        projection::Projection::new(self, fields)
+    }
+
+    /// Current and hourly entries whose reported dew point differs from the computed one by more
+    /// than `tolerance` (in `units`)
+    pub fn dew_point_mismatches(
+        &self,
+        units: Units,
+        tolerance: f64,
+    ) -> Vec<derive::DewPointMismatch> {
+        let current = self
+            .current
+            .iter()
+            .map(|x| (&x.dt, x.dew_point, x.computed_dew_point(units)));
+        let hourly = self
+            .hourly
+            .iter()
+            .flatten()
+            .map(|x| (&x.dt, x.dew_point, x.computed_dew_point(units)));
+
+        current
+            .chain(hourly)
+            .filter(|(_, reported, computed)| (reported - computed).abs() > tolerance)
+            .map(|(dt, reported, computed)| derive::DewPointMismatch {
+                dt: dt.clone(),
+                reported,
+                computed,
+            })
+            .collect()
+    }
 }
 
 /// Current weather data API response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Current {
     /// Current time, unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub dt: Zoned,
 
-    /// Sunrise time, unix, UTC
-    #[serde(with = "ts_seconds")]
-    pub sunrise: Zoned,
+    /// Sunrise time, unix, UTC. Missing during polar day and night.
+    #[serde(default, with = "ts_seconds_option")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
+    pub sunrise: Option<Zoned>,
 
-    /// Sunset time, unix, UTC
-    #[serde(with = "ts_seconds")]
-    pub sunset: Zoned,
+    /// Sunset time, unix, UTC. Missing during polar day and night.
+    #[serde(default, with = "ts_seconds_option")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
+    pub sunset: Option<Zoned>,
 
     /// Temperature. Unit Default: Kelvin, Metric: Celsius, Imperial: Fahrenheit.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub temp: f64,
 
     /// Temperature. This temperature parameter accounts for the human perception of weather.
     ///
     /// Unit Default: Kelvin, Metric: Celsius, Imperial: Fahrenheit.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub feels_like: f64,
 
     /// Atmospheric pressure on the sea level, hPa
+    #[serde(deserialize_with = "integer::deserialize")]
     pub pressure: u16,
 
     /// Humidity, %
+    #[serde(deserialize_with = "integer::deserialize")]
     pub humidity: u8,
 
     /// Atmospheric temperature (varying according to pressure and humidity) below which water droplets begin to condense and dew can form. Units – default: kelvin, metric: Celsius, imperial: Fahrenheit.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub dew_point: f64,
 
     /// Cloudiness, %
+    #[serde(deserialize_with = "integer::deserialize")]
     pub clouds: u8,
 
     /// Current UV index
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub uvi: f64,
 
-    /// Average visibility, metres. The maximum value of the visibility is 10km
-    pub visibility: Option<u16>,
+    /// Average visibility, metres. Usually at most 10km, though larger values are sometimes reported
+    #[serde(default, deserialize_with = "integer::option::deserialize")]
+    pub visibility: Option<u32>,
 
     /// Wind speed. Unit Default: meter/sec, Metric: meter/sec, Imperial: miles/hour.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub wind_speed: f64,
 
     /// (where available) Wind gust. Units – default: metre/sec, metric: metre/sec, imperial: miles/hour. [How to change units used](https://openweathermap.org/api/one-call-api#data)
+    #[serde(default, deserialize_with = "lenient::option::deserialize")]
     pub wind_gust: Option<f64>,
 
     /// Wind direction, degrees (meteorological)
+    #[serde(deserialize_with = "integer::deserialize")]
     pub wind_deg: u16,
 
     /// (where available) Rain volume for last hour, mm
+    #[serde(default, deserialize_with = "lenient::precipitation::deserialize")]
     pub rain: Option<Precipitation>,
 
     /// (where available) Snow volume for last hour, mm
+    #[serde(default, deserialize_with = "lenient::precipitation::deserialize")]
     pub snow: Option<Precipitation>,
 
     pub weather: Vec<WeatherElement>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Current {
+    /// Whether `dt` is between sunrise and sunset, or `None` without both times, such as during
+    /// polar day and night
+    pub fn is_day(&self) -> Option<bool> {
+        let (sunrise, sunset) = (self.sunrise.as_ref()?, self.sunset.as_ref()?);
+        Some(*sunrise <= self.dt && self.dt < *sunset)
+    }
+
+    /// Time from `dt` until sunset. This is zero after sunset, and the full day before sunrise.
+    /// `None` without both times.
+    pub fn daylight_remaining(&self) -> Option<Span> {
+        let (sunrise, sunset) = (self.sunrise.as_ref()?, self.sunset.as_ref()?);
+        Some(if self.dt >= *sunset {
+            Span::new()
+        } else if self.dt < *sunrise {
+            sunset - sunrise
+        } else {
+            sunset - &self.dt
+        })
+    }
+
+    /// Difference between the temperature and the dew point, in the units of the request
+    pub fn dew_point_spread(&self) -> f64 {
+        self.temp - self.dew_point
+    }
+
+    /// Visibility in kilometres
+    pub fn visibility_km(&self) -> Option<f64> {
+        self.visibility.map(|x| f64::from(x) / 1000.0)
+    }
+
+    /// Fog risk from the dew point spread, humidity, and visibility
+    pub fn fog_risk(&self, units: Units) -> aviation::FogRisk {
+        let spread = units.to_celsius(self.temp) - units.to_celsius(self.dew_point);
+        aviation::fog_risk(spread, self.humidity, self.visibility)
+    }
+
+    /// NWS heat index, in `units`
+    pub fn heat_index(&self, units: Units) -> units::Temperature {
+        let temp = units.to_celsius(self.temp);
+        let index = derive::heat_index(temp, f64::from(self.humidity));
+        units::Temperature::new(units.from_celsius(index), units)
+    }
+
+    /// North American wind chill index, in `units`, where defined
+    pub fn wind_chill(&self, units: Units) -> Option<units::Temperature> {
+        let temp = units.to_celsius(self.temp);
+        let index = derive::wind_chill(temp, self.wind(units).kilometres_per_hour())?;
+        Some(units::Temperature::new(units.from_celsius(index), units))
+    }
+
+    /// Canadian humidex, in `units`
+    pub fn humidex(&self, units: Units) -> units::Temperature {
+        let temp = units.to_celsius(self.temp);
+        let index = derive::humidex(temp, units.to_celsius(self.dew_point));
+        units::Temperature::new(units.from_celsius(index), units)
+    }
+
+    /// WHO exposure category of the UV index
+    pub fn uv_category(&self) -> uv::UvCategory {
+        uv::UvCategory::from_uvi(self.uvi)
+    }
+
+    /// Beaufort force of the wind speed, given the `units` of the request
+    pub fn beaufort(&self, units: Units) -> wind::Beaufort {
+        self.wind(units).into()
+    }
+
+    /// Wind direction as a compass point
+    pub fn wind_direction(&self) -> wind::CompassDirection {
+        self.wind_deg.into()
+    }
+
+    /// Temperature, tagged with the `units` of the request
+    pub fn temperature(&self, units: Units) -> units::Temperature {
+        units::Temperature::new(self.temp, units)
+    }
+
+    /// Feels like temperature, tagged with the `units` of the request
+    pub fn feels_like_temperature(&self, units: Units) -> units::Temperature {
+        units::Temperature::new(self.feels_like, units)
+    }
+
+    /// Wind speed, tagged with the `units` of the request
+    pub fn wind(&self, units: Units) -> units::WindSpeed {
+        units::WindSpeed::new(self.wind_speed, units)
+    }
+
+    /// Wind gust, tagged with the `units` of the request
+    pub fn gust(&self, units: Units) -> Option<units::WindSpeed> {
+        self.wind_gust.map(|x| units::WindSpeed::new(x, units))
+    }
+
+    /// Dew point computed from `temp` and `humidity`, in `units`
+    pub fn computed_dew_point(&self, units: Units) -> f64 {
+        let temp = units.to_celsius(self.temp);
+        units.from_celsius(derive::dew_point(temp, f64::from(self.humidity)))
+    }
+
+    /// Pressure at `elevation` metres (QFE), hPa
+    pub fn station_pressure(&self, elevation: f64) -> f64 {
+        aviation::station_pressure(f64::from(self.pressure), elevation)
+    }
+
+    /// Pressure altitude at `elevation` metres, metres
+    pub fn pressure_altitude(&self, elevation: f64) -> f64 {
+        aviation::pressure_altitude(self.station_pressure(elevation))
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct WeatherElement {
     /// Weather condition id
+    #[serde(deserialize_with = "integer::deserialize")]
     pub id: i64,
 
     /// Group of weather parameters (Rain, Snow, Extreme, etc.)
@@ -169,9 +820,23 @@ pub struct WeatherElement {
 
     /// Weather icon id.
     pub icon: String,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+impl WeatherElement {
+    /// Emoji for the condition, by day or night as the icon indicates, or `None` for an unknown
+    /// condition id
+    pub fn emoji(&self) -> Option<&'static str> {
+        emoji::for_element(self.id, &self.icon)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
 pub enum Main {
     Thunderstorm,
     Drizzle,
@@ -191,102 +856,315 @@ pub enum Main {
 }
 
 /// Minute forecast weather data API response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Minutely {
     /// Time of the forecasted data, Unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub dt: Zoned,
 
-    /// Precipitation volume, mm
+    /// Precipitation intensity, mm/h
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub precipitation: f64,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// Hourly forecast weather data API response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Hourly {
     /// Time of the forecasted data, Unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub dt: Zoned,
 
     /// Temperature. Unit Default: Kelvin, Metric: Celsius, Imperial: Fahrenheit. [How
     /// to change units used](https://openweathermap.org/api/one-call-api#data)
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub temp: f64,
 
     /// Temperature. This temperature parameter accounts for the human perception of weather.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub feels_like: f64,
 
     /// Atmospheric pressure on the sea level. hPa
+    #[serde(deserialize_with = "integer::deserialize")]
     pub pressure: u16,
 
     /// Humidity, %
+    #[serde(deserialize_with = "integer::deserialize")]
     pub humidity: u8,
 
     /// Atmospheric temperature (varying according to pressure and humidity) below which water droplets begin to condense and dew can form. Units – default: kelvin, metric: Celsius, imperial: Fahrenheit.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub dew_point: f64,
 
-    /// UVI index
-    pub uvi: f64,
+    /// UVI index. Missing from some historical payloads.
+    #[serde(default, deserialize_with = "lenient::option::deserialize")]
+    pub uvi: Option<f64>,
 
     /// Cloudiness, %
+    #[serde(deserialize_with = "integer::deserialize")]
     pub clouds: u8,
 
-    /// Average visibility, metres. The maximum value of the visibility is 10km
-    pub visibility: Option<u16>,
+    /// Average visibility, metres. Usually at most 10km, though larger values are sometimes reported
+    #[serde(default, deserialize_with = "integer::option::deserialize")]
+    pub visibility: Option<u32>,
 
     /// Wind speed. Units – default: metre/sec, metric: metre/sec, imperial: miles/hour. [How to change units used](https://openweathermap.org/api/one-call-api#data)
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub wind_speed: f64,
 
     /// (where available) Wind gust. Units – default: metre/sec, metric: metre/sec, imperial: miles/hour. [How to change units used](https://openweathermap.org/api/one-call-api#data)
+    #[serde(default, deserialize_with = "lenient::option::deserialize")]
     pub wind_gust: Option<f64>,
 
     /// Wind direction, degrees (meteorological)
+    #[serde(deserialize_with = "integer::deserialize")]
     pub wind_deg: u16,
 
-    /// Probability of precipitation. The values of the parameter vary between 0 and 1, where 0 is equal to 0%, 1 is equal to 100%
-    pub pop: f64,
+    /// Probability of precipitation. The values of the parameter vary between 0 and 1, where 0 is equal to 0%, 1 is equal to 100%. Missing from some historical payloads.
+    #[serde(default, deserialize_with = "lenient::option::deserialize")]
+    pub pop: Option<f64>,
 
     /// (where available) Rain volume for last hour, mm
+    #[serde(default, deserialize_with = "lenient::precipitation::deserialize")]
     pub rain: Option<Precipitation>,
 
     /// (where available) Snow volume for last hour, mm
+    #[serde(default, deserialize_with = "lenient::precipitation::deserialize")]
     pub snow: Option<Precipitation>,
 
     /// Hourly weather element
     pub weather: Vec<WeatherElement>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+impl Hourly {
+    /// Whether `dt` is between sunrise and sunset of the enclosing day in `daily`, which is
+    /// expected to be in order. Returns `None` if `daily` is empty, or the day doesn't have both
+    /// times.
+    pub fn is_day(&self, daily: &[Daily]) -> Option<bool> {
+        let day = daily
+            .iter()
+            .take_while(|x| *x.sunrise.as_ref().unwrap_or(&x.dt) <= self.dt)
+            .last()
+            .or(daily.first())?;
+        let (sunrise, sunset) = (day.sunrise.as_ref()?, day.sunset.as_ref()?);
+        Some(*sunrise <= self.dt && self.dt < *sunset)
+    }
+
+    /// Difference between the temperature and the dew point, in the units of the request
+    pub fn dew_point_spread(&self) -> f64 {
+        self.temp - self.dew_point
+    }
+
+    /// Visibility in kilometres
+    pub fn visibility_km(&self) -> Option<f64> {
+        self.visibility.map(|x| f64::from(x) / 1000.0)
+    }
+
+    /// Fog risk from the dew point spread, humidity, and visibility
+    pub fn fog_risk(&self, units: Units) -> aviation::FogRisk {
+        let spread = units.to_celsius(self.temp) - units.to_celsius(self.dew_point);
+        aviation::fog_risk(spread, self.humidity, self.visibility)
+    }
+
+    /// NWS heat index, in `units`
+    pub fn heat_index(&self, units: Units) -> units::Temperature {
+        let temp = units.to_celsius(self.temp);
+        let index = derive::heat_index(temp, f64::from(self.humidity));
+        units::Temperature::new(units.from_celsius(index), units)
+    }
+
+    /// North American wind chill index, in `units`, where defined
+    pub fn wind_chill(&self, units: Units) -> Option<units::Temperature> {
+        let temp = units.to_celsius(self.temp);
+        let index = derive::wind_chill(temp, self.wind(units).kilometres_per_hour())?;
+        Some(units::Temperature::new(units.from_celsius(index), units))
+    }
+
+    /// Canadian humidex, in `units`
+    pub fn humidex(&self, units: Units) -> units::Temperature {
+        let temp = units.to_celsius(self.temp);
+        let index = derive::humidex(temp, units.to_celsius(self.dew_point));
+        units::Temperature::new(units.from_celsius(index), units)
+    }
+
+    /// WHO exposure category of the UV index, if the entry has one
+    pub fn uv_category(&self) -> Option<uv::UvCategory> {
+        self.uvi.map(uv::UvCategory::from_uvi)
+    }
+
+    /// Beaufort force of the wind speed, given the `units` of the request
+    pub fn beaufort(&self, units: Units) -> wind::Beaufort {
+        self.wind(units).into()
+    }
+
+    /// Wind direction as a compass point
+    pub fn wind_direction(&self) -> wind::CompassDirection {
+        self.wind_deg.into()
+    }
+
+    /// Temperature, tagged with the `units` of the request
+    pub fn temperature(&self, units: Units) -> units::Temperature {
+        units::Temperature::new(self.temp, units)
+    }
+
+    /// Feels like temperature, tagged with the `units` of the request
+    pub fn feels_like_temperature(&self, units: Units) -> units::Temperature {
+        units::Temperature::new(self.feels_like, units)
+    }
+
+    /// Wind speed, tagged with the `units` of the request
+    pub fn wind(&self, units: Units) -> units::WindSpeed {
+        units::WindSpeed::new(self.wind_speed, units)
+    }
+
+    /// Wind gust, tagged with the `units` of the request
+    pub fn gust(&self, units: Units) -> Option<units::WindSpeed> {
+        self.wind_gust.map(|x| units::WindSpeed::new(x, units))
+    }
+
+    /// Dew point computed from `temp` and `humidity`, in `units`
+    pub fn computed_dew_point(&self, units: Units) -> f64 {
+        let temp = units.to_celsius(self.temp);
+        units.from_celsius(derive::dew_point(temp, f64::from(self.humidity)))
+    }
+
+    /// Pressure at `elevation` metres (QFE), hPa
+    pub fn station_pressure(&self, elevation: f64) -> f64 {
+        aviation::station_pressure(f64::from(self.pressure), elevation)
+    }
+
+    /// Pressure altitude at `elevation` metres, metres
+    pub fn pressure_altitude(&self, elevation: f64) -> f64 {
+        aviation::pressure_altitude(self.station_pressure(elevation))
+    }
+
+    /// Combined rain and snow volume for the hour, as [`Precipitation::volume`] counts it. Zero
+    /// if neither was reported.
+    pub fn total_precipitation(&self) -> units::Precipitation {
+        let volume = |x: &Option<Precipitation>| x.as_ref().map_or(0.0, |x| x.volume().0);
+        units::Precipitation(volume(&self.rain) + volume(&self.snow))
+    }
+}
+
+/// Rain or snow volume
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Precipitation {
-    #[serde(rename = "1h")]
-    pub one_hour: f64,
+    /// Volume for the last hour, mm. Reported by One Call and current weather data.
+    #[serde(
+        rename = "1h",
+        default,
+        deserialize_with = "lenient::option::deserialize"
+    )]
+    pub one_hour: Option<f64>,
+
+    /// Volume for the last 3 hours, mm. Reported by the 5 day forecast, current weather data,
+    /// and some archived 2.5 responses.
+    #[serde(
+        rename = "3h",
+        default,
+        deserialize_with = "lenient::option::deserialize"
+    )]
+    pub three_hours: Option<f64>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Precipitation {
+    /// Volume per hour: the volume for the last hour, or if only the volume for the last 3 hours
+    /// was reported, as in cached 2.5 data, a third of it. Zero if neither was.
+    ///
+    /// Spreading a 3 hour volume evenly keeps sums over hourly entries from counting it three
+    /// times over.
+    pub fn volume(&self) -> units::Precipitation {
+        units::Precipitation(self.amount_mm())
+    }
+
+    /// [`volume`](Self::volume) in millimetres, whichever shape the volume was reported in
+    pub fn amount_mm(&self) -> f64 {
+        let three_hours = self.three_hours.map(|x| x / 3.0);
+        self.one_hour.or(three_hours).unwrap_or_default()
+    }
+
+    /// A volume reported as a plain number, for the last 3 hours or the last hour
+    pub(crate) fn from_amount(amount: f64, three_hours: bool) -> Self {
+        Self {
+            one_hour: (!three_hours).then_some(amount),
+            three_hours: three_hours.then_some(amount),
+            #[cfg(feature = "extra-fields")]
+            extra: Default::default(),
+        }
+    }
 }
 
 /// Daily forecast weather data API response
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Daily {
     /// Time of the forecasted data, Unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub dt: Zoned,
 
-    /// Sunrise time, Unix, UTC
-    #[serde(with = "ts_seconds")]
-    pub sunrise: Zoned,
+    /// Sunrise time, Unix, UTC. Missing during polar day and night.
+    #[serde(default, with = "ts_seconds_option")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
+    pub sunrise: Option<Zoned>,
 
-    /// Sunset time, Unix, UTC
-    #[serde(with = "ts_seconds")]
-    pub sunset: Zoned,
+    /// Sunset time, Unix, UTC. Missing during polar day and night.
+    #[serde(default, with = "ts_seconds_option")]
+    #[cfg_attr(feature = "schemars", schemars(with = "Option<i64>"))]
+    pub sunset: Option<Zoned>,
 
     /// The time of when the moon sets for the day, Unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub moonrise: Zoned,
 
     /// The time of when the moon sets for the day, Unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub moonset: Zoned,
 
     /// Moon phase. `0` and `1` are 'new moon', `0.25` is 'first quarter moon', `0.5` is 'full moon' and `0.75` is 'last quarter moon'. The periods in between are called 'waxing crescent', 'waxing gibous', 'waning gibous', and 'waning crescent', respectively.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub moon_phase: f64,
 
+    /// (where available) Human-readable description of the weather conditions for the day
+    pub summary: Option<String>,
+
     /// Units – default: kelvin, metric: Celsius, imperial: Fahrenheit. [How to change units used](https://openweathermap.org/api/one-call-api#data)
     pub temp: DailyTemperature,
 
@@ -294,80 +1172,202 @@ pub struct Daily {
     pub feels_like: DailyFeelsLikeTemperature,
 
     /// Atmospheric pressure on the sea level. hPa
+    #[serde(deserialize_with = "integer::deserialize")]
     pub pressure: u16,
 
     /// Humidity, %
+    #[serde(deserialize_with = "integer::deserialize")]
     pub humidity: u8,
 
     /// Atmospheric temperature (varying according to pressure and humidity) below which water droplets begin to condense and dew can form. Units – default: kelvin, metric: Celsius, imperial: Fahrenheit.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub dew_point: f64,
 
     /// Wind speed. Units – default: metre/sec, metric: metre/sec, imperial: miles/hour. [How to change units used](https://openweathermap.org/api/one-call-api#data)
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub wind_speed: f64,
 
     /// (where available) Wind gust. Units – default: metre/sec, metric: metre/sec, imperial: miles/hour. [How to change units used](https://openweathermap.org/api/one-call-api#data)
+    #[serde(default, deserialize_with = "lenient::option::deserialize")]
     pub wind_gust: Option<f64>,
 
     /// Wind direction, degrees (meteorological)
+    #[serde(deserialize_with = "integer::deserialize")]
     pub wind_deg: u16,
 
     /// Cloudiness, %
+    #[serde(deserialize_with = "integer::deserialize")]
     pub clouds: u8,
 
     /// The maximum value of UV index for the day
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub uvi: f64,
 
-    /// Probability of precipitation. The values of the parameter vary between 0 and 1, where 0 is equal to 0%, 1 is equal to 100%
-    pub pop: f64,
+    /// Probability of precipitation. The values of the parameter vary between 0 and 1, where 0 is equal to 0%, 1 is equal to 100%. Missing from some historical payloads.
+    #[serde(default, deserialize_with = "lenient::option::deserialize")]
+    pub pop: Option<f64>,
 
     /// (where available) Precipitation volume, mm
+    #[serde(default, deserialize_with = "lenient::amount::deserialize")]
     pub rain: Option<f64>,
 
     /// (where available) Snow volume, mm
+    #[serde(default, deserialize_with = "lenient::amount::deserialize")]
     pub snow: Option<f64>,
 
     /// Hourly weather elements
     pub weather: Vec<WeatherElement>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Daily {
+    /// Time between sunrise and sunset, or `None` without both times
+    pub fn daylight(&self) -> Option<Span> {
+        Some(self.sunset.as_ref()? - self.sunrise.as_ref()?)
+    }
+
+    /// Midpoint between sunrise and sunset, or `None` without both times
+    pub fn solar_noon(&self) -> Option<Zoned> {
+        let (sunrise, sunset) = (self.sunrise.as_ref()?, self.sunset.as_ref()?);
+        let half = (sunset.timestamp().as_second() - sunrise.timestamp().as_second()) / 2;
+        Some(sunrise.saturating_add(Span::new().seconds(half)))
+    }
+
+    /// WHO exposure category of the UV index
+    pub fn uv_category(&self) -> uv::UvCategory {
+        uv::UvCategory::from_uvi(self.uvi)
+    }
+
+    /// Named phase of [`moon_phase`](Self::moon_phase)
+    pub fn moon_phase_name(&self) -> moon::MoonPhase {
+        moon::MoonPhase::from_value(self.moon_phase)
+    }
+
+    /// Beaufort force of the wind speed, given the `units` of the request
+    pub fn beaufort(&self, units: Units) -> wind::Beaufort {
+        self.wind(units).into()
+    }
+
+    /// Wind direction as a compass point
+    pub fn wind_direction(&self) -> wind::CompassDirection {
+        self.wind_deg.into()
+    }
+
+    /// Wind speed, tagged with the `units` of the request
+    pub fn wind(&self, units: Units) -> units::WindSpeed {
+        units::WindSpeed::new(self.wind_speed, units)
+    }
+
+    /// Wind gust, tagged with the `units` of the request
+    pub fn gust(&self, units: Units) -> Option<units::WindSpeed> {
+        self.wind_gust.map(|x| units::WindSpeed::new(x, units))
+    }
+
+    /// Rain volume
+    pub fn rain_volume(&self) -> Option<units::Precipitation> {
+        self.rain.map(units::Precipitation)
+    }
+
+    /// Snow volume
+    pub fn snow_volume(&self) -> Option<units::Precipitation> {
+        self.snow.map(units::Precipitation)
+    }
+
+    /// Combined rain and snow volume, zero if neither was reported
+    pub fn total_precipitation(&self) -> units::Precipitation {
+        units::Precipitation(self.rain.unwrap_or_default() + self.snow.unwrap_or_default())
+    }
+}
+
+impl DailyTemperature {
+    /// Minimum daily temperature, tagged with the `units` of the request
+    pub fn min_temperature(&self, units: Units) -> units::Temperature {
+        units::Temperature::new(self.min, units)
+    }
+
+    /// Maximum daily temperature, tagged with the `units` of the request
+    pub fn max_temperature(&self, units: Units) -> units::Temperature {
+        units::Temperature::new(self.max, units)
+    }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct DailyTemperature {
     /// Morning temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub morn: f64,
 
     /// Day temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub day: f64,
 
     /// Evening temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub eve: f64,
 
     /// Night temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub night: f64,
 
     /// Min daily temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub min: f64,
 
     /// Max daily temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub max: f64,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct DailyFeelsLikeTemperature {
     /// Morning temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub morn: f64,
 
     /// Day temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub day: f64,
 
     /// Evening temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub eve: f64,
 
     /// Night temperature.
+    #[serde(deserialize_with = "lenient::deserialize")]
     pub night: f64,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 /// National weather alerts data from major national weather warning systems
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Deserialize, Serialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
 pub struct Alert {
     /// Name of the alert source. Please read here the [full list of alert sources](https://openweathermap.org/api/one-call-3#listsource)
     pub sender_name: String,
@@ -377,10 +1377,12 @@ pub struct Alert {
 
     /// Date and time of the start of the alert, Unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub start: Zoned,
 
     /// Date and time of the end of the alert, Unix, UTC
     #[serde(with = "ts_seconds")]
+    #[cfg_attr(feature = "schemars", schemars(with = "i64"))]
     pub end: Zoned,
 
     /// Description of the alert
@@ -388,6 +1390,33 @@ pub struct Alert {
 
     /// Type of severe weather
     pub tags: Vec<String>,
+
+    /// Fields this crate doesn't model yet
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl Alert {
+    /// Whether `time` is from [`start`](Self::start) until [`end`](Self::end)
+    pub fn is_active_at(&self, time: &Zoned) -> bool {
+        &self.start <= time && *time < self.end
+    }
+
+    /// Whether any point from `start` until `end` is while the alert is in effect
+    pub fn overlaps(&self, start: &Zoned, end: &Zoned) -> bool {
+        &self.start < end && *start < self.end
+    }
+
+    /// Severity, urgency, affected period, and instructions, where they can be detected
+    pub fn parsed(&self) -> alerts::AlertDetails {
+        alerts::AlertDetails::parse(&self.event, &self.description)
+    }
+
+    /// [`tags`](Self::tags) parsed into known types of severe weather
+    pub fn parsed_tags(&self) -> Vec<alerts::AlertTag> {
+        self.tags.iter().map(|x| x.as_str().into()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -411,4 +1440,321 @@ mod tests {
 
         assert_eq!(expected, foo.dt);
     }
+
+    #[test]
+    fn parse_timestamp_from_string_or_float() {
+        let expected = Timestamp::from_second(1721581200).unwrap();
+        for json in [
+            r#"{ "dt": "1721581200" }"#,
+            r#"{ "dt": 1721581200.0 }"#,
+            r#"{ "dt": " 1721581200 " }"#,
+        ] {
+            let foo: Foo = serde_json::from_str(json).unwrap();
+            assert_eq!(expected, foo.dt.timestamp(), "{json}");
+        }
+        assert!(serde_json::from_str::<Foo>(r#"{ "dt": "noon" }"#).is_err());
+    }
+
+    #[test]
+    fn integer_fields_accept_strings() {
+        let json = crate::fixtures::FULL_JSON
+            .replacen(
+                r#""timezone_offset": -18000"#,
+                r#""timezone_offset": "-18000""#,
+                1,
+            )
+            .replacen(r#""id": 802"#, r#""id": "802""#, 1);
+        let weather: Weather = serde_json::from_str(&json).unwrap();
+        assert_eq!(-18000, weather.timezone_offset);
+        assert_eq!(802, weather.current.unwrap().weather[0].id);
+    }
+
+    #[cfg(all(feature = "strict", not(feature = "extra-fields")))]
+    #[test]
+    fn reject_unknown_fields() {
+        let json = crate::fixtures::FULL_JSON.replacen('{', r#"{ "elevation": 239, "#, 1);
+        let error = serde_json::from_str::<Weather>(&json).unwrap_err();
+        assert!(error.to_string().starts_with("unknown field `elevation`"));
+    }
+
+    #[cfg(feature = "extra-fields")]
+    #[test]
+    fn keep_unknown_fields() {
+        let json = crate::fixtures::FULL_JSON.replacen('{', r#"{ "elevation": 239, "#, 1);
+        let weather: Weather = serde_json::from_str(&json).unwrap();
+        assert_eq!(
+            Some(&serde_json::json!(239)),
+            weather.extra.get("elevation")
+        );
+        assert!(weather.current.unwrap().extra.is_empty());
+    }
+
+    #[cfg(feature = "simd-json")]
+    #[test]
+    fn parse_with_simd_json() {
+        let json = crate::fixtures::FULL_JSON;
+        let weather = Weather::from_simd_slice(&mut json.as_bytes().to_vec()).unwrap();
+        let expected: Weather = serde_json::from_str(json).unwrap();
+        assert_eq!(
+            serde_json::to_value(expected).unwrap(),
+            serde_json::to_value(weather).unwrap()
+        );
+    }
+
+    #[cfg(feature = "schemars")]
+    #[test]
+    fn json_schema() {
+        let schema = serde_json::to_value(schemars::schema_for!(Weather)).unwrap();
+        let hourly = &schema["definitions"]["Hourly"];
+        assert_eq!("integer", hourly["properties"]["dt"]["type"]);
+        assert!(hourly["required"]
+            .as_array()
+            .unwrap()
+            .contains(&serde_json::json!("temp")));
+        assert!(schema["definitions"]["Precipitation"]["properties"]
+            .get("1h")
+            .is_some());
+    }
+
+    #[test]
+    fn geojson() {
+        let weather = crate::fixtures::full();
+        let feature = weather.to_geojson();
+        assert_eq!("Feature", feature["type"]);
+        assert_eq!(
+            serde_json::json!([weather.lon, weather.lat]),
+            feature["geometry"]["coordinates"]
+        );
+        let properties = &feature["properties"];
+        assert_eq!(
+            serde_json::json!(weather.current.as_ref().unwrap().temp),
+            properties["current"]["temp"]
+        );
+        assert_eq!(
+            weather.alerts.as_ref().unwrap().len(),
+            properties["alerts"].as_array().unwrap().len()
+        );
+    }
+
+    #[test]
+    fn classify_errors() {
+        let error: OwmError = serde_json::from_str(
+            r#"{ "cod": 400, "message": "wrong latitude", "parameters": ["lat"] }"#,
+        )
+        .unwrap();
+        assert_eq!(ErrorKind::BadRequest, error.kind());
+        assert_eq!(Some(vec!["lat".to_string()]), error.parameters);
+
+        let error: OwmError = serde_json::from_str(
+            r#"{ "cod": "401", "message": "Invalid API key. Please see https://openweathermap.org/faq#error401 for more info." }"#,
+        )
+        .unwrap();
+        assert_eq!(ErrorKind::InvalidApiKey, error.kind());
+        assert_eq!(None, error.parameters);
+
+        let json = r#"{ "cod": 404, "message": "city not found" }"#;
+        let error = parse_response::<Weather>(json).unwrap_err();
+        assert!(matches!(error, Error::NotFound));
+        let error = parse_response::<Weather>(r#"{ "lat": "north" }"#).unwrap_err();
+        assert!(matches!(error, Error::Deserialize { .. }));
+    }
+
+    #[test]
+    fn daylight() {
+        let weather = crate::fixtures::full();
+
+        let daily = &weather.daily.as_ref().unwrap()[0];
+        assert_eq!(
+            Span::new().hours(16).minutes(5).seconds(59),
+            daily.daylight().unwrap().round(jiff::Unit::Second).unwrap()
+        );
+        assert_eq!(
+            1721587989,
+            daily.solar_noon().unwrap().timestamp().as_second()
+        );
+
+        let current = weather.current.unwrap();
+        assert_eq!(
+            34569,
+            current
+                .daylight_remaining()
+                .unwrap()
+                .total(jiff::Unit::Second)
+                .unwrap() as i64
+        );
+    }
+
+    #[test]
+    fn wide_visibility() {
+        let json = crate::fixtures::FULL_JSON.replacen(
+            r#""visibility": 10000"#,
+            r#""visibility": "70000.0""#,
+            1,
+        );
+        let weather: Weather = serde_json::from_str(&json).unwrap();
+        assert_eq!(Some(70.0), weather.current.unwrap().visibility_km());
+        assert_eq!(Some(10.0), weather.hourly.unwrap()[0].visibility_km());
+    }
+
+    #[test]
+    fn three_hour_volumes() {
+        let weather = crate::fixtures::full();
+        let mut hourly = weather.hourly.unwrap().remove(0);
+        hourly.rain = Some(Precipitation::from_amount(1.5, true));
+        hourly.snow = Some(Precipitation::from_amount(0.25, false));
+        assert_eq!(0.5, hourly.rain.as_ref().unwrap().volume().millimetres());
+        assert_eq!(0.75, hourly.total_precipitation().millimetres());
+    }
+
+    #[test]
+    fn day_and_night() {
+        let weather = crate::fixtures::full();
+        assert_eq!(Some(true), weather.current.unwrap().is_day());
+
+        let mut daily = weather.daily.unwrap();
+        let hourly = weather.hourly.unwrap();
+        assert_eq!(Some(true), hourly[0].is_day(&daily));
+
+        // Pretend the sun set early
+        daily[0].sunset = Some(hourly[2].dt.clone());
+        assert_eq!(Some(true), hourly[1].is_day(&daily));
+        assert_eq!(Some(false), hourly[2].is_day(&daily));
+        assert_eq!(None, hourly[2].is_day(&[]));
+
+        // The midnight sun
+        let weather = crate::fixtures::polar();
+        let current = weather.current.unwrap();
+        assert!(current.sunrise.is_none() && current.sunset.is_none());
+        assert_eq!(None, current.is_day());
+        assert_eq!(None, weather.daily.unwrap()[0].daylight());
+    }
+
+    #[test]
+    fn slice_series() {
+        let weather = crate::fixtures::full();
+        let hourly = weather.hourly.as_ref().unwrap();
+        let daily = weather.daily.as_ref().unwrap();
+
+        let hours: Vec<_> = weather
+            .hours_between(&hourly[1].dt, &hourly[3].dt)
+            .collect();
+        assert_eq!(2, hours.len());
+        assert_eq!(hourly[2].dt, hours[1].dt);
+
+        let time = &hourly[2].dt + Span::new().minutes(59);
+        assert_eq!(hourly[2].dt, weather.hour_at(&time).unwrap().dt);
+        assert!(weather
+            .hour_at(&(&hourly[5].dt + Span::new().hours(1)))
+            .is_none());
+
+        let date = jiff::civil::date(2024, 7, 22);
+        assert_eq!(daily[1].dt, weather.day_at(date).unwrap().dt);
+        assert_eq!(
+            2,
+            weather
+                .days_between(date, jiff::civil::date(2024, 8, 1))
+                .count()
+        );
+        assert!(weather.day_at(jiff::civil::date(2024, 7, 20)).is_none());
+
+        // An hour starting at the last representable time ends there
+        let mut weather = weather;
+        let last = jiff::Timestamp::MAX.to_zoned(TimeZone::UTC);
+        weather.hourly.as_mut().unwrap()[5].dt = last.clone();
+        assert!(weather.hour_at(&last).is_none());
+
+        // Winnipeg is back on standard time, an hour behind the offset of the response
+        let dt: Zoned = "2024-11-04T05:30:00Z[UTC]".parse().unwrap();
+        weather.daily.as_mut().unwrap()[2].dt = dt.clone();
+        assert_eq!(
+            dt,
+            weather.day_at(jiff::civil::date(2024, 11, 3)).unwrap().dt
+        );
+    }
+
+    #[test]
+    fn missing_location() {
+        let mut json: serde_json::Value = serde_json::from_str(crate::fixtures::FULL_JSON).unwrap();
+        let object = json.as_object_mut().unwrap();
+        for key in ["lat", "lon", "timezone", "timezone_offset"] {
+            object.remove(key);
+        }
+        let weather: Weather = serde_json::from_value(json).unwrap();
+        assert_eq!(0.0, weather.lat);
+        assert_eq!(TimeZone::UTC, weather.time_zone());
+    }
+
+    #[test]
+    fn active_alerts() {
+        let weather = crate::fixtures::full();
+        let alert = &weather.alerts.as_ref().unwrap()[0];
+        let before = &alert.start - Span::new().hours(1);
+
+        assert_eq!(1, weather.alerts_active_at(&alert.start).count());
+        assert_eq!(0, weather.alerts_active_at(&alert.end).count());
+        assert_eq!(0, weather.alerts_active_at(&before).count());
+        assert_eq!(1, weather.alerts_overlapping(&before, &alert.end).count());
+        assert_eq!(0, weather.alerts_overlapping(&before, &alert.start).count());
+    }
+
+    #[test]
+    fn wire_format() {
+        let weather = crate::fixtures::full();
+        insta::assert_json_snapshot!(weather);
+
+        // Serialized models must parse back into the same thing
+        let json = serde_json::to_string(&weather).unwrap();
+        let parsed: Weather = serde_json::from_str(&json).unwrap();
+        assert_eq!(json, serde_json::to_string(&parsed).unwrap());
+    }
+
+    /// Assert that `value` reads back the same from bincode, CBOR, and MessagePack
+    #[cfg(not(feature = "extra-fields"))]
+    fn assert_binary_round_trips<T>(value: &T)
+    where
+        T: Serialize + serde::de::DeserializeOwned,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        let config = bincode::config::standard();
+
+        let bytes = bincode::serde::encode_to_vec(value, config).unwrap();
+        let (parsed, _): (T, _) = bincode::serde::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(json, serde_json::to_string(&parsed).unwrap());
+
+        let mut bytes = Vec::new();
+        ciborium::into_writer(value, &mut bytes).unwrap();
+        let parsed: T = ciborium::from_reader(&bytes[..]).unwrap();
+        assert_eq!(json, serde_json::to_string(&parsed).unwrap());
+
+        let bytes = rmp_serde::to_vec(value).unwrap();
+        let parsed: T = rmp_serde::from_slice(&bytes).unwrap();
+        assert_eq!(json, serde_json::to_string(&parsed).unwrap());
+    }
+
+    #[cfg(not(feature = "extra-fields"))]
+    #[test]
+    fn binary_round_trips() {
+        for json in [
+            crate::fixtures::FULL_JSON,
+            crate::fixtures::EXCLUDED_SECTIONS_JSON,
+            crate::fixtures::POLAR_JSON,
+        ] {
+            let weather: Weather = serde_json::from_str(json).unwrap();
+            assert_binary_round_trips(&weather);
+        }
+
+        #[cfg(feature = "current-weather")]
+        assert_binary_round_trips(
+            &serde_json::from_str::<current_weather::CurrentWeather>(include_str!(
+                "../fixtures/current_weather.json"
+            ))
+            .unwrap(),
+        );
+
+        #[cfg(feature = "forecast")]
+        assert_binary_round_trips(
+            &serde_json::from_str::<forecast::Forecast>(include_str!("../fixtures/forecast.json"))
+                .unwrap(),
+        );
+    }
 }
@@ -0,0 +1,94 @@
+//! URLs of [Weather Maps](https://openweathermap.org/api/weathermaps) tiles.
+//!
+//! Tiles are 256×256 PNGs in the usual `{z}/{x}/{y}` slippy map scheme, so they can be laid over
+//! a base map as is. The URL carries the API key; use
+//! [`debug::redact_api_key`](crate::debug::redact_api_key) before logging one.
+//!
+//! ```
+//! use owm_rs::{maps::{self, Layer}, ApiKey};
+//!
+//! let key = ApiKey::new("abc123");
+//! assert_eq!(
+//!     "https://tile.openweathermap.org/map/clouds_new/3/2/5.png?appid=abc123",
+//!     maps::tile_url(Layer::Clouds, 3, 2, 5, &key)
+//! );
+//! ```
+
+use crate::ApiKey;
+use std::fmt;
+
+/// A Weather Maps layer
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Layer {
+    Clouds,
+    Precipitation,
+    /// Sea level pressure
+    Pressure,
+    /// Wind speed
+    Wind,
+    /// Air temperature
+    Temperature,
+}
+
+impl Layer {
+    pub const ALL: [Self; 5] = [
+        Self::Clouds,
+        Self::Precipitation,
+        Self::Pressure,
+        Self::Wind,
+        Self::Temperature,
+    ];
+
+    /// Name of the layer in tile URLs
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Clouds => "clouds_new",
+            Self::Precipitation => "precipitation_new",
+            Self::Pressure => "pressure_new",
+            Self::Wind => "wind_new",
+            Self::Temperature => "temp_new",
+        }
+    }
+}
+
+impl fmt::Display for Layer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// URL of the tile of `layer` at zoom level `z`, column `x`, and row `y`
+///
+/// `x` and `y` range from 0 to 2<sup>`z`</sup> − 1, from the top left of the map.
+pub fn tile_url(layer: Layer, z: u8, x: u32, y: u32, key: &ApiKey) -> String {
+    format!(
+        "https://tile.openweathermap.org/map/{layer}/{z}/{x}/{y}.png?appid={}",
+        key.expose()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tile_urls() {
+        let key = ApiKey::new("abc123");
+        let names: Vec<&str> = Layer::ALL.iter().map(|x| x.name()).collect();
+        assert_eq!(
+            vec![
+                "clouds_new",
+                "precipitation_new",
+                "pressure_new",
+                "wind_new",
+                "temp_new"
+            ],
+            names
+        );
+        let url = tile_url(Layer::Temperature, 0, 0, 0, &key);
+        assert_eq!(
+            "https://tile.openweathermap.org/map/temp_new/0/0/0.png?appid=REDACTED",
+            crate::debug::redact_api_key(&url)
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! [Prometheus](https://docs.rs/prometheus) gauges of the latest conditions, for running inside
+//! monitoring agents.
+//!
+//! [`WeatherCollector`] keeps one gauge of each metric per location, labelled with the `lat`,
+//! `lon`, and `timezone` of the response. Values are in base units, as Prometheus recommends:
+//!
+//! | Metric | Value |
+//! | --- | --- |
+//! | `owm_temperature_celsius` | Temperature |
+//! | `owm_feels_like_celsius` | Perceived temperature |
+//! | `owm_humidity_ratio` | Humidity, from 0 to 1 |
+//! | `owm_pressure_pascals` | Atmospheric pressure on the sea level |
+//! | `owm_wind_speed_meters_per_second` | Wind speed |
+//! | `owm_wind_gust_meters_per_second` | Wind gust, where reported |
+//! | `owm_wind_direction_degrees` | Wind direction, meteorological |
+//! | `owm_uv_index` | UV index |
+//! | `owm_alerts` | Number of alerts in the response |
+//! | `owm_observed_timestamp_seconds` | Time of the conditions, unix |
+//!
+//! ```
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../fixtures/onecall.json"))?;
+//! use owm_rs::{metrics::WeatherCollector, Units};
+//!
+//! let collector = WeatherCollector::new()?;
+//! let registry = prometheus::Registry::new();
+//! registry.register(Box::new(collector.clone()))?;
+//!
+//! collector.update(&weather, Units::Metric);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+use crate::{Units, Weather};
+use prometheus::{core::Collector, core::Desc, proto::MetricFamily, GaugeVec, Opts};
+
+const LABELS: [&str; 3] = ["lat", "lon", "timezone"];
+
+/// Gauges of the latest conditions at each location. Clones share the same gauges, so a clone can
+/// be registered while the original is updated.
+#[derive(Debug, Clone)]
+pub struct WeatherCollector {
+    temperature: GaugeVec,
+    feels_like: GaugeVec,
+    humidity: GaugeVec,
+    pressure: GaugeVec,
+    wind_speed: GaugeVec,
+    wind_gust: GaugeVec,
+    wind_direction: GaugeVec,
+    uv_index: GaugeVec,
+    alerts: GaugeVec,
+    observed: GaugeVec,
+}
+
+impl WeatherCollector {
+    pub fn new() -> prometheus::Result<Self> {
+        let gauge = |name: &str, help: &str| GaugeVec::new(Opts::new(name, help), &LABELS);
+        Ok(Self {
+            temperature: gauge("owm_temperature_celsius", "Temperature")?,
+            feels_like: gauge("owm_feels_like_celsius", "Perceived temperature")?,
+            humidity: gauge("owm_humidity_ratio", "Humidity, from 0 to 1")?,
+            pressure: gauge(
+                "owm_pressure_pascals",
+                "Atmospheric pressure on the sea level",
+            )?,
+            wind_speed: gauge("owm_wind_speed_meters_per_second", "Wind speed")?,
+            wind_gust: gauge("owm_wind_gust_meters_per_second", "Wind gust")?,
+            wind_direction: gauge(
+                "owm_wind_direction_degrees",
+                "Wind direction, meteorological",
+            )?,
+            uv_index: gauge("owm_uv_index", "UV index")?,
+            alerts: gauge("owm_alerts", "Number of alerts")?,
+            observed: gauge(
+                "owm_observed_timestamp_seconds",
+                "Time of the conditions, unix",
+            )?,
+        })
+    }
+
+    /// Set the gauges of `weather`'s location from its current conditions and alerts, with
+    /// values in the `units` of the request
+    ///
+    /// Gauges for values the response doesn't have, such as the current conditions when they're
+    /// excluded, are removed for the location rather than left at their previous value.
+    pub fn update(&self, weather: &Weather, units: Units) {
+        let lat = format!("{:.4}", weather.lat);
+        let lon = format!("{:.4}", weather.lon);
+        let labels = [lat.as_str(), lon.as_str(), weather.timezone.as_str()];
+        let set = |gauge: &GaugeVec, value: Option<f64>| match value {
+            Some(value) => gauge.with_label_values(&labels).set(value),
+            None => {
+                // Not having a gauge for the location yet isn't an error
+                let _ = gauge.remove_label_values(&labels);
+            }
+        };
+
+        let current = weather.current.as_ref();
+        set(&self.temperature, current.map(|x| units.to_celsius(x.temp)));
+        set(
+            &self.feels_like,
+            current.map(|x| units.to_celsius(x.feels_like)),
+        );
+        set(
+            &self.humidity,
+            current.map(|x| f64::from(x.humidity) / 100.0),
+        );
+        set(
+            &self.pressure,
+            current.map(|x| f64::from(x.pressure) * 100.0),
+        );
+        set(
+            &self.wind_speed,
+            current.map(|x| units.to_metres_per_second(x.wind_speed)),
+        );
+        set(
+            &self.wind_gust,
+            current
+                .and_then(|x| x.wind_gust)
+                .map(|x| units.to_metres_per_second(x)),
+        );
+        set(&self.wind_direction, current.map(|x| f64::from(x.wind_deg)));
+        set(&self.uv_index, current.map(|x| x.uvi));
+        set(
+            &self.observed,
+            current.map(|x| x.dt.timestamp().as_second() as f64),
+        );
+        set(
+            &self.alerts,
+            Some(weather.alerts.as_ref().map_or(0, Vec::len) as f64),
+        );
+    }
+
+    fn gauges(&self) -> [&GaugeVec; 10] {
+        [
+            &self.temperature,
+            &self.feels_like,
+            &self.humidity,
+            &self.pressure,
+            &self.wind_speed,
+            &self.wind_gust,
+            &self.wind_direction,
+            &self.uv_index,
+            &self.alerts,
+            &self.observed,
+        ]
+    }
+}
+
+impl Collector for WeatherCollector {
+    fn desc(&self) -> Vec<&Desc> {
+        self.gauges().into_iter().flat_map(|x| x.desc()).collect()
+    }
+
+    fn collect(&self) -> Vec<MetricFamily> {
+        self.gauges()
+            .into_iter()
+            .flat_map(|x| x.collect())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collect_gauges() {
+        let mut weather = crate::fixtures::full();
+        let collector = WeatherCollector::new().unwrap();
+        let registry = prometheus::Registry::new();
+        registry.register(Box::new(collector.clone())).unwrap();
+
+        collector.update(&weather, Units::Metric);
+        let families = registry.gather();
+        let family = |name: &str| families.iter().find(|x| x.name() == name).cloned();
+        let temperature = family("owm_temperature_celsius").unwrap();
+        let metric = &temperature.get_metric()[0];
+        assert_eq!(
+            weather.current.as_ref().unwrap().temp,
+            metric.get_gauge().get_value()
+        );
+        let labels: Vec<(&str, &str)> = metric
+            .get_label()
+            .iter()
+            .map(|x| (x.name(), x.value()))
+            .collect();
+        assert_eq!(
+            vec![
+                ("lat", format!("{:.4}", weather.lat).as_str()),
+                ("lon", format!("{:.4}", weather.lon).as_str()),
+                ("timezone", weather.timezone.as_str()),
+            ],
+            labels
+        );
+        assert_eq!(
+            weather.alerts.as_ref().unwrap().len() as f64,
+            family("owm_alerts").unwrap().get_metric()[0]
+                .get_gauge()
+                .get_value()
+        );
+
+        weather.current = None;
+        collector.update(&weather, Units::Metric);
+        assert!(registry
+            .gather()
+            .iter()
+            .all(|x| x.name() != "owm_temperature_celsius"));
+    }
+}
@@ -0,0 +1,135 @@
+//! Validation and repair of minutely forecast series.
+//!
+//! Minutely forecasts should have one entry per minute, but real responses occasionally skip
+//! minutes or repeat them.
+
+use crate::Minutely;
+use jiff::{Span, Zoned};
+
+/// Longest gap [`repair`] fills, in seconds: the hour a minutely forecast covers
+const MAX_GAP: i64 = 60 * 60;
+
+/// A problem found in a minutely series
+#[derive(Debug, PartialEq)]
+pub enum MinutelyIssue {
+    /// Minutes missing between `after` and the next entry
+    Gap { after: Zoned, missing: i64 },
+
+    /// More than one entry for the same minute
+    Duplicate { dt: Zoned },
+
+    /// An entry earlier than the one before it
+    OutOfOrder { dt: Zoned },
+}
+
+/// Find gaps, duplicates, and out of order entries in `series`
+pub fn check(series: &[Minutely]) -> Vec<MinutelyIssue> {
+    series
+        .windows(2)
+        .filter_map(|pair| {
+            let (a, b) = (&pair[0], &pair[1]);
+            match seconds_between(a, b) {
+                0 => Some(MinutelyIssue::Duplicate { dt: b.dt.clone() }),
+                x if x < 0 => Some(MinutelyIssue::OutOfOrder { dt: b.dt.clone() }),
+                x if x > 60 => Some(MinutelyIssue::Gap {
+                    after: a.dt.clone(),
+                    missing: (x - 1) / 60,
+                }),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Sort `series`, keep the last of any duplicate entries, and fill gaps by linearly
+/// interpolating the precipitation of the surrounding entries
+///
+/// Gaps longer than the hour a forecast covers can only come from a corrupt series, and are left
+/// as they are.
+pub fn repair(mut series: Vec<Minutely>) -> Vec<Minutely> {
+    series.sort_by(|a, b| a.dt.cmp(&b.dt));
+
+    let mut repaired: Vec<Minutely> = Vec::with_capacity(series.len());
+    for entry in series {
+        let Some(previous) = repaired.last_mut() else {
+            repaired.push(entry);
+            continue;
+        };
+
+        let seconds = seconds_between(previous, &entry);
+        if seconds == 0 {
+            *previous = entry;
+            continue;
+        }
+
+        if seconds > MAX_GAP {
+            repaired.push(entry);
+            continue;
+        }
+
+        let (start, precipitation) = (previous.dt.clone(), previous.precipitation);
+        for minute in 1..=(seconds - 1) / 60 {
+            let fraction = (minute * 60) as f64 / seconds as f64;
+            repaired.push(Minutely {
+                dt: start.saturating_add(Span::new().minutes(minute)),
+                precipitation: precipitation + (entry.precipitation - precipitation) * fraction,
+                #[cfg(feature = "extra-fields")]
+                extra: Default::default(),
+            });
+        }
+        repaired.push(entry);
+    }
+    repaired
+}
+
+fn seconds_between(a: &Minutely, b: &Minutely) -> i64 {
+    b.dt.timestamp().as_second() - a.dt.timestamp().as_second()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_and_repair() {
+        let series: Vec<Minutely> = serde_json::from_str(
+            r#"[
+                { "dt": 1721582400, "precipitation": 0 },
+                { "dt": 1721582460, "precipitation": 0.3 },
+                { "dt": 1721582460, "precipitation": 0.2 },
+                { "dt": 1721582640, "precipitation": 0.8 },
+                { "dt": 1721582520, "precipitation": 0.4 }
+            ]"#,
+        )
+        .unwrap();
+
+        let issues = check(&series);
+        assert_eq!(3, issues.len());
+        assert!(matches!(issues[0], MinutelyIssue::Duplicate { .. }));
+        assert!(matches!(issues[1], MinutelyIssue::Gap { missing: 2, .. }));
+        assert!(matches!(issues[2], MinutelyIssue::OutOfOrder { .. }));
+
+        let repaired = repair(series);
+        assert!(check(&repaired).is_empty());
+        let precipitation: Vec<_> = repaired.iter().map(|x| x.precipitation).collect();
+        assert_eq!(5, precipitation.len());
+        assert_eq!(0.2, precipitation[1]);
+        assert!((precipitation[3] - 0.6).abs() < 1e-9);
+    }
+
+    #[test]
+    fn large_gap() {
+        let series: Vec<Minutely> = serde_json::from_str(
+            r#"[
+                { "dt": 1721582400, "precipitation": 0 },
+                { "dt": 1721586000, "precipitation": 0.6 },
+                { "dt": 253402207200, "precipitation": 0 }
+            ]"#,
+        )
+        .unwrap();
+
+        let repaired = repair(series);
+        assert_eq!(62, repaired.len());
+        assert!(matches!(check(&repaired)[..], [MinutelyIssue::Gap { .. }]));
+    }
+}
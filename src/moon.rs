@@ -0,0 +1,78 @@
+//! Moon phase names.
+
+use std::fmt;
+
+/// Phase of the moon, as described by OWM's `moon_phase` value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MoonPhase {
+    NewMoon,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    FullMoon,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    /// Phase for a `moon_phase` value. `0` and `1` are new moon, `0.25` is first quarter, `0.5`
+    /// is full moon, and `0.75` is last quarter; values in between are the intermediate phases.
+    /// Values outside of `0..=1` wrap around.
+    pub fn from_value(value: f64) -> Self {
+        let value = value.rem_euclid(1.0);
+        if value == 0.0 {
+            Self::NewMoon
+        } else if value < 0.25 {
+            Self::WaxingCrescent
+        } else if value == 0.25 {
+            Self::FirstQuarter
+        } else if value < 0.5 {
+            Self::WaxingGibbous
+        } else if value == 0.5 {
+            Self::FullMoon
+        } else if value < 0.75 {
+            Self::WaningGibbous
+        } else if value == 0.75 {
+            Self::LastQuarter
+        } else {
+            Self::WaningCrescent
+        }
+    }
+
+    /// Name of the phase, such as `"Waxing crescent"`
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::NewMoon => "New moon",
+            Self::WaxingCrescent => "Waxing crescent",
+            Self::FirstQuarter => "First quarter",
+            Self::WaxingGibbous => "Waxing gibbous",
+            Self::FullMoon => "Full moon",
+            Self::WaningGibbous => "Waning gibbous",
+            Self::LastQuarter => "Last quarter",
+            Self::WaningCrescent => "Waning crescent",
+        }
+    }
+}
+
+impl fmt::Display for MoonPhase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn phase_from_value() {
+        assert_eq!(MoonPhase::NewMoon, MoonPhase::from_value(0.0));
+        assert_eq!(MoonPhase::NewMoon, MoonPhase::from_value(1.0));
+        assert_eq!(MoonPhase::WaxingCrescent, MoonPhase::from_value(0.03));
+        assert_eq!(MoonPhase::FirstQuarter, MoonPhase::from_value(0.25));
+        assert_eq!(MoonPhase::FullMoon, MoonPhase::from_value(0.5));
+        assert_eq!(MoonPhase::WaningGibbous, MoonPhase::from_value(0.53));
+        assert_eq!(MoonPhase::WaningCrescent, MoonPhase::from_value(0.97));
+    }
+}
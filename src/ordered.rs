@@ -0,0 +1,179 @@
+//! Chronological ordering of the minutely, hourly, and daily series.
+//!
+//! OWM sends every series in order, but cached or hand-merged payloads may not be. Either repair a
+//! response with [`Weather::sort_series`], or reject it while parsing by using [`deserialize`] in
+//! place of [`Weather`]'s own `Deserialize` implementation:
+//!
+//! ```
+//! # let json = include_str!("../fixtures/onecall.json");
+//! let weather = owm_rs::ordered::deserialize(&mut serde_json::Deserializer::from_str(json))?;
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{alerts::same_alert, Weather};
+use jiff::Zoned;
+use serde::{de, Deserialize, Deserializer};
+
+/// Deserialize a [`Weather`], failing unless every series is strictly increasing by `dt`
+pub fn deserialize<'de, D>(d: D) -> Result<Weather, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let weather = Weather::deserialize(d)?;
+
+    let series = [
+        ("minutely", times(&weather.minutely, |x| &x.dt)),
+        ("hourly", times(&weather.hourly, |x| &x.dt)),
+        ("daily", times(&weather.daily, |x| &x.dt)),
+    ];
+    for (name, times) in series {
+        if let Some(i) = times.windows(2).position(|x| x[0] >= x[1]) {
+            return Err(de::Error::custom(format!(
+                "{name} entry {} is not after the one before it",
+                i + 1
+            )));
+        }
+    }
+
+    Ok(weather)
+}
+
+fn times<T>(series: &Option<Vec<T>>, dt: impl Fn(&T) -> &Zoned) -> Vec<&Zoned> {
+    series.iter().flatten().map(dt).collect()
+}
+
+pub(crate) fn merge(older: Weather, newer: Weather) -> Weather {
+    fn union<T>(older: Option<Vec<T>>, newer: Option<Vec<T>>) -> Option<Vec<T>> {
+        match (older, newer) {
+            (Some(mut older), Some(newer)) => {
+                older.extend(newer);
+                Some(older)
+            }
+            (older, newer) => newer.or(older),
+        }
+    }
+
+    let mut alerts = union(older.alerts, newer.alerts);
+    if let Some(alerts) = &mut alerts {
+        // Keep the newer copy of alerts that appear in both
+        let keep: Vec<bool> = (0..alerts.len())
+            .map(|i| !alerts[i + 1..].iter().any(|x| same_alert(&alerts[i], x)))
+            .collect();
+        let mut keep = keep.into_iter();
+        alerts.retain(|_| keep.next().unwrap_or(true));
+    }
+
+    let mut merged = Weather {
+        lat: newer.lat,
+        lon: newer.lon,
+        timezone: newer.timezone,
+        timezone_offset: newer.timezone_offset,
+        current: newer.current.or(older.current),
+        minutely: union(older.minutely, newer.minutely),
+        hourly: union(older.hourly, newer.hourly),
+        daily: union(older.daily, newer.daily),
+        alerts,
+        #[cfg(feature = "extra-fields")]
+        extra: newer.extra,
+    };
+    sort_series(&mut merged);
+    merged
+}
+
+pub(crate) fn sort_series(weather: &mut Weather) {
+    if let Some(x) = &mut weather.minutely {
+        sort_dedup(x, |x| &x.dt);
+    }
+    if let Some(x) = &mut weather.hourly {
+        sort_dedup(x, |x| &x.dt);
+    }
+    if let Some(x) = &mut weather.daily {
+        sort_dedup(x, |x| &x.dt);
+    }
+    if let Some(x) = &mut weather.alerts {
+        x.sort_by(|a, b| a.start.cmp(&b.start));
+    }
+}
+
+/// Stable sort by `dt`, keeping the last of any entries with the same time
+fn sort_dedup<T>(series: &mut Vec<T>, dt: impl Fn(&T) -> &Zoned) {
+    series.sort_by(|a, b| dt(a).cmp(dt(b)));
+    series.reverse();
+    series.dedup_by(|a, b| dt(a) == dt(b));
+    series.reverse();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sort_and_reject() {
+        let json = crate::fixtures::FULL_JSON;
+        let mut weather: Weather = serde_json::from_str(json).unwrap();
+        let hourly = weather.hourly.as_mut().unwrap();
+        let expected: Vec<_> = hourly.iter().map(|x| x.dt.clone()).collect();
+        hourly.swap(0, 3);
+        hourly[4].dt = hourly[5].dt.clone();
+        hourly[4].temp = -99.0;
+
+        let json: serde_json::Value = serde_json::to_value(&weather).unwrap();
+        let error = deserialize(&json).unwrap_err();
+        assert_eq!(
+            "hourly entry 1 is not after the one before it",
+            error.to_string()
+        );
+
+        weather.sort_series();
+        let hourly = weather.hourly.unwrap();
+        assert_eq!(
+            vec![
+                &expected[0],
+                &expected[1],
+                &expected[2],
+                &expected[3],
+                &expected[5]
+            ],
+            hourly.iter().map(|x| &x.dt).collect::<Vec<_>>()
+        );
+        assert_ne!(-99.0, hourly[4].temp);
+    }
+
+    #[test]
+    fn merge_responses() {
+        let json = crate::fixtures::FULL_JSON;
+        let older: Weather = serde_json::from_str(json).unwrap();
+        let mut newer: Weather = serde_json::from_str(json).unwrap();
+
+        // The newer response starts two hours later and has an extra alert
+        let hourly = newer.hourly.as_mut().unwrap();
+        hourly.drain(..2);
+        for x in hourly.iter_mut() {
+            x.dt = &x.dt + jiff::Span::new().hours(2);
+        }
+        hourly[0].temp = -99.0;
+        let mut alert: crate::Alert = serde_json::from_value(
+            serde_json::to_value(&newer.alerts.as_ref().unwrap()[0]).unwrap(),
+        )
+        .unwrap();
+        alert.event = "Heat Warning".to_string();
+        newer.alerts.as_mut().unwrap().push(alert);
+
+        let older_times: Vec<_> = older
+            .hourly
+            .iter()
+            .flatten()
+            .map(|x| x.dt.clone())
+            .collect();
+        let merged = older.merge(newer);
+        let hourly = merged.hourly.unwrap();
+        assert_eq!(8, hourly.len());
+        assert_eq!(
+            older_times[..],
+            hourly.iter().map(|x| x.dt.clone()).collect::<Vec<_>>()[..6]
+        );
+        assert_eq!(-99.0, hourly[4].temp);
+        assert_eq!(3, merged.daily.unwrap().len());
+        assert_eq!(2, merged.alerts.unwrap().len());
+    }
+}
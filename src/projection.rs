@@ -0,0 +1,247 @@
+//! Slimmed down, serializable views of a [`Weather`] response.
+//!
+//! Useful for re-serving weather data to clients where every byte counts. Each entry is
+//! serialized as a flat map of the selected fields, keyed by [`Field::name`], with `dt` as a
+//! unix timestamp in seconds. Fields that don't apply to an entry, or that are missing from it,
+//! are left out.
+
+use crate::{Current, Daily, Hourly, Precipitation, Weather};
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+/// A field that can be selected for a [`Projection`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    /// Temperature. For daily entries, this is the day temperature.
+    Temp,
+
+    /// Minimum daily temperature. Only applies to daily entries.
+    TempMin,
+
+    /// Maximum daily temperature. Only applies to daily entries.
+    TempMax,
+
+    /// Feels like temperature. For daily entries, this is the day temperature.
+    FeelsLike,
+    Pressure,
+    Humidity,
+    DewPoint,
+    Clouds,
+    Uvi,
+    Visibility,
+    WindSpeed,
+    WindGust,
+    WindDeg,
+
+    /// Probability of precipitation. Doesn't apply to current entries.
+    Pop,
+
+    /// Rain volume, mm. Per hour for current and hourly entries, as
+    /// [`Precipitation::amount_mm`] counts it.
+    Rain,
+
+    /// Snow volume, mm. Per hour for current and hourly entries, as
+    /// [`Precipitation::amount_mm`] counts it.
+    Snow,
+
+    /// Weather condition id of the primary weather element
+    Condition,
+}
+
+impl Field {
+    /// Key used when serializing this field
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Temp => "temp",
+            Self::TempMin => "temp_min",
+            Self::TempMax => "temp_max",
+            Self::FeelsLike => "feels_like",
+            Self::Pressure => "pressure",
+            Self::Humidity => "humidity",
+            Self::DewPoint => "dew_point",
+            Self::Clouds => "clouds",
+            Self::Uvi => "uvi",
+            Self::Visibility => "visibility",
+            Self::WindSpeed => "wind_speed",
+            Self::WindGust => "wind_gust",
+            Self::WindDeg => "wind_deg",
+            Self::Pop => "pop",
+            Self::Rain => "rain",
+            Self::Snow => "snow",
+            Self::Condition => "condition",
+        }
+    }
+}
+
+/// Selected fields of a [`Weather`] response
+#[derive(Debug, Serialize)]
+pub struct Projection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub current: Option<Entry>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub hourly: Vec<Entry>,
+
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub daily: Vec<Entry>,
+}
+
+/// Selected fields of a single current, hourly, or daily entry
+#[derive(Debug)]
+pub struct Entry {
+    /// Time of the entry, unix, UTC
+    pub dt: i64,
+
+    /// Values of the selected fields that apply to this entry
+    pub values: Vec<(Field, f64)>,
+}
+
+impl Serialize for Entry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.values.len() + 1))?;
+        map.serialize_entry("dt", &self.dt)?;
+        for (field, value) in &self.values {
+            map.serialize_entry(field.name(), value)?;
+        }
+        map.end()
+    }
+}
+
+impl Projection {
+    pub(crate) fn new(weather: &Weather, fields: &[Field]) -> Self {
+        Self {
+            current: weather.current.as_ref().map(|x| {
+                Entry::new(x.dt.timestamp().as_second(), fields, |f| {
+                    current_value(x, f)
+                })
+            }),
+            hourly: weather
+                .hourly
+                .iter()
+                .flatten()
+                .map(|x| Entry::new(x.dt.timestamp().as_second(), fields, |f| hourly_value(x, f)))
+                .collect(),
+            daily: weather
+                .daily
+                .iter()
+                .flatten()
+                .map(|x| Entry::new(x.dt.timestamp().as_second(), fields, |f| daily_value(x, f)))
+                .collect(),
+        }
+    }
+}
+
+impl Entry {
+    fn new(dt: i64, fields: &[Field], value: impl Fn(Field) -> Option<f64>) -> Self {
+        Self {
+            dt,
+            values: fields
+                .iter()
+                .filter_map(|&f| value(f).map(|x| (f, x)))
+                .collect(),
+        }
+    }
+}
+
+pub(crate) fn current_value(x: &Current, field: Field) -> Option<f64> {
+    match field {
+        Field::Temp => Some(x.temp),
+        Field::FeelsLike => Some(x.feels_like),
+        Field::Pressure => Some(x.pressure.into()),
+        Field::Humidity => Some(x.humidity.into()),
+        Field::DewPoint => Some(x.dew_point),
+        Field::Clouds => Some(x.clouds.into()),
+        Field::Uvi => Some(x.uvi),
+        Field::Visibility => x.visibility.map(Into::into),
+        Field::WindSpeed => Some(x.wind_speed),
+        Field::WindGust => x.wind_gust,
+        Field::WindDeg => Some(x.wind_deg.into()),
+        Field::Rain => x.rain.as_ref().map(Precipitation::amount_mm),
+        Field::Snow => x.snow.as_ref().map(Precipitation::amount_mm),
+        Field::Condition => x.weather.first().map(|x| x.id as f64),
+        Field::TempMin | Field::TempMax | Field::Pop => None,
+    }
+}
+
+pub(crate) fn hourly_value(x: &Hourly, field: Field) -> Option<f64> {
+    match field {
+        Field::Temp => Some(x.temp),
+        Field::FeelsLike => Some(x.feels_like),
+        Field::Pressure => Some(x.pressure.into()),
+        Field::Humidity => Some(x.humidity.into()),
+        Field::DewPoint => Some(x.dew_point),
+        Field::Clouds => Some(x.clouds.into()),
+        Field::Uvi => x.uvi,
+        Field::Visibility => x.visibility.map(Into::into),
+        Field::WindSpeed => Some(x.wind_speed),
+        Field::WindGust => x.wind_gust,
+        Field::WindDeg => Some(x.wind_deg.into()),
+        Field::Pop => x.pop,
+        Field::Rain => x.rain.as_ref().map(Precipitation::amount_mm),
+        Field::Snow => x.snow.as_ref().map(Precipitation::amount_mm),
+        Field::Condition => x.weather.first().map(|x| x.id as f64),
+        Field::TempMin | Field::TempMax => None,
+    }
+}
+
+pub(crate) fn daily_value(x: &Daily, field: Field) -> Option<f64> {
+    match field {
+        Field::Temp => Some(x.temp.day),
+        Field::TempMin => Some(x.temp.min),
+        Field::TempMax => Some(x.temp.max),
+        Field::FeelsLike => Some(x.feels_like.day),
+        Field::Pressure => Some(x.pressure.into()),
+        Field::Humidity => Some(x.humidity.into()),
+        Field::DewPoint => Some(x.dew_point),
+        Field::Clouds => Some(x.clouds.into()),
+        Field::Uvi => Some(x.uvi),
+        Field::WindSpeed => Some(x.wind_speed),
+        Field::WindGust => x.wind_gust,
+        Field::WindDeg => Some(x.wind_deg.into()),
+        Field::Pop => x.pop,
+        Field::Rain => x.rain,
+        Field::Snow => x.snow,
+        Field::Condition => x.weather.first().map(|x| x.id as f64),
+        Field::Visibility => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_fields() {
+        let weather = crate::fixtures::full();
+        let projection = weather.project(&[Field::Temp, Field::Pop]);
+
+        let json = serde_json::to_value(&projection).unwrap();
+        assert_eq!(
+            serde_json::json!({ "dt": 1721582400, "temp": 27.3 }),
+            json["current"]
+        );
+        assert_eq!(
+            serde_json::json!({ "dt": 1721584800, "temp": 28.4, "pop": 0.54 }),
+            json["hourly"][1]
+        );
+        assert_eq!(3, projection.daily.len());
+    }
+    #[test]
+    fn rain_matches_totals() {
+        let mut weather = crate::fixtures::full();
+        let hourly = &mut weather.hourly.as_mut().unwrap()[0];
+        hourly.rain = Some(Precipitation::from_amount(1.5, true));
+        hourly.snow = None;
+
+        let projection = weather.project(&[Field::Rain]);
+        let json = serde_json::to_value(&projection).unwrap();
+        let total = weather.hourly.as_ref().unwrap()[0].total_precipitation();
+        assert_eq!(
+            serde_json::json!(total.millimetres()),
+            json["hourly"][0]["rain"]
+        );
+        assert_eq!(serde_json::json!(0.5), json["hourly"][0]["rain"]);
+    }
+}
@@ -0,0 +1,136 @@
+//! Tracking how much of a subscription's quota has been used.
+//!
+//! A [`QuotaTracker`] counts calls per endpoint by UTC day and over the last minute, so
+//! long-running programs can budget their polling. It implements `Serialize` and `Deserialize`
+//! so the counts can be persisted across restarts in any serde format.
+
+use crate::rate_limit::{Endpoint, Plan};
+use jiff::{civil::Date, tz::TimeZone, Timestamp};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, time::Duration};
+
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+const MINUTE: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct Usage {
+    /// UTC day that `today` counts calls for
+    day: Date,
+    today: u32,
+
+    /// Times of the calls in the last minute
+    recent: Vec<Timestamp>,
+}
+
+/// Calls made per endpoint, compared to the limits of a [`Plan`]
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct QuotaTracker {
+    plan: Plan,
+    usage: HashMap<Endpoint, Usage>,
+}
+
+impl QuotaTracker {
+    pub fn new(plan: Plan) -> Self {
+        Self {
+            plan,
+            usage: HashMap::new(),
+        }
+    }
+
+    /// Count a call to `endpoint` made now
+    pub fn record(&mut self, endpoint: Endpoint) {
+        self.record_at(endpoint, Timestamp::now());
+    }
+
+    /// Count a call to `endpoint` made at `time`
+    pub fn record_at(&mut self, endpoint: Endpoint, time: Timestamp) {
+        let day = utc_date(time);
+        let usage = self.usage.entry(endpoint).or_insert(Usage {
+            day,
+            today: 0,
+            recent: Vec::new(),
+        });
+        if usage.day != day {
+            usage.day = day;
+            usage.today = 0;
+        }
+        usage.today += 1;
+        usage.recent.retain(|x| within_minute(*x, time));
+        usage.recent.push(time);
+    }
+
+    /// Calls to `endpoint` so far on the UTC day of `time`
+    pub fn calls_today(&self, endpoint: Endpoint, time: Timestamp) -> u32 {
+        self.usage
+            .get(&endpoint)
+            .filter(|x| x.day == utc_date(time))
+            .map_or(0, |x| x.today)
+    }
+
+    /// Calls to `endpoint` in the minute before `time`
+    pub fn calls_last_minute(&self, endpoint: Endpoint, time: Timestamp) -> u32 {
+        self.usage.get(&endpoint).map_or(0, |x| {
+            x.recent.iter().filter(|x| within_minute(**x, time)).count() as u32
+        })
+    }
+
+    /// Calls left today for `endpoint`, if the plan has a daily limit for it
+    pub fn remaining_today(&self, endpoint: Endpoint) -> Option<u32> {
+        let limit = self
+            .plan
+            .limits
+            .get(&endpoint)
+            .filter(|x| x.period == DAY)?;
+        let used = self.calls_today(endpoint, Timestamp::now());
+        Some(limit.calls.saturating_sub(used))
+    }
+
+    /// Calls left in the current minute for `endpoint`, if the plan has a per minute limit for it
+    pub fn remaining_this_minute(&self, endpoint: Endpoint) -> Option<u32> {
+        let limit = self
+            .plan
+            .limits
+            .get(&endpoint)
+            .filter(|x| x.period == MINUTE)?;
+        let used = self.calls_last_minute(endpoint, Timestamp::now());
+        Some(limit.calls.saturating_sub(used))
+    }
+}
+
+fn utc_date(time: Timestamp) -> Date {
+    time.to_zoned(TimeZone::UTC).date()
+}
+
+fn within_minute(call: Timestamp, time: Timestamp) -> bool {
+    let seconds = time.as_second() - call.as_second();
+    (0..60).contains(&seconds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn track_calls() {
+        let mut tracker = QuotaTracker::new(Plan::free());
+        let now = Timestamp::now();
+        tracker.record_at(Endpoint::OneCall, now);
+        tracker.record_at(Endpoint::OneCall, now);
+        tracker.record_at(Endpoint::CurrentWeather, now);
+        assert_eq!(Some(998), tracker.remaining_today(Endpoint::OneCall));
+        assert_eq!(None, tracker.remaining_today(Endpoint::CurrentWeather));
+        assert_eq!(
+            Some(59),
+            tracker.remaining_this_minute(Endpoint::CurrentWeather)
+        );
+
+        // Counts survive a round trip, and reset on the next day
+        let json = serde_json::to_string(&tracker).unwrap();
+        let mut tracker: QuotaTracker = serde_json::from_str(&json).unwrap();
+        assert_eq!(2, tracker.calls_today(Endpoint::OneCall, now));
+        let tomorrow = now + jiff::Span::new().hours(24);
+        tracker.record_at(Endpoint::OneCall, tomorrow);
+        assert_eq!(1, tracker.calls_today(Endpoint::OneCall, tomorrow));
+        assert_eq!(1, tracker.calls_last_minute(Endpoint::OneCall, tomorrow));
+    }
+}
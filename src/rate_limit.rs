@@ -0,0 +1,217 @@
+//! Client-side rate limiting to stay within a subscription's quotas.
+//!
+//! OWM answers calls past a quota with 429 errors, which still count against some plans. A
+//! [`RateLimiter`] keeps a token bucket per endpoint so calls can be delayed or skipped before
+//! they're made. Call [`RateLimiter::try_acquire`] or [`RateLimiter::acquire`] before each
+//! request.
+
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+/// An API endpoint with its own quota
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+pub enum Endpoint {
+    /// One Call API, including timemachine and day summary calls
+    OneCall,
+
+    /// Current weather data
+    CurrentWeather,
+
+    /// 5 day / 3 hour forecast
+    Forecast,
+}
+
+/// At most `calls` calls per `period`
+///
+/// A limit of zero calls allows none at all, and a zero period doesn't limit anything.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub struct Limit {
+    pub calls: u32,
+    pub period: Duration,
+}
+
+impl Limit {
+    pub fn per_minute(calls: u32) -> Self {
+        Self {
+            calls,
+            period: Duration::from_secs(60),
+        }
+    }
+
+    pub fn per_day(calls: u32) -> Self {
+        Self {
+            calls,
+            period: Duration::from_secs(24 * 60 * 60),
+        }
+    }
+}
+
+/// Limits of a subscription plan, by endpoint. Endpoints without a limit aren't limited.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct Plan {
+    pub limits: HashMap<Endpoint, Limit>,
+}
+
+impl Plan {
+    /// The free tier: 1,000 One Call calls a day, and 60 calls a minute to other endpoints
+    pub fn free() -> Self {
+        Self::default()
+            .with(Endpoint::OneCall, Limit::per_day(1000))
+            .with(Endpoint::CurrentWeather, Limit::per_minute(60))
+            .with(Endpoint::Forecast, Limit::per_minute(60))
+    }
+
+    /// This plan, with `endpoint` limited to `limit`
+    pub fn with(mut self, endpoint: Endpoint, limit: Limit) -> Self {
+        self.limits.insert(endpoint, limit);
+        self
+    }
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Token bucket rate limiter for the endpoints of a [`Plan`]
+///
+/// Each bucket starts full, so a whole period's worth of calls can be made at once, and refills
+/// continuously over the period.
+#[derive(Debug)]
+pub struct RateLimiter {
+    plan: Plan,
+    buckets: HashMap<Endpoint, Bucket>,
+}
+
+impl RateLimiter {
+    pub fn new(plan: Plan) -> Self {
+        Self {
+            plan,
+            buckets: HashMap::new(),
+        }
+    }
+
+    /// Take a call from `endpoint`'s quota, or return how long to wait until one is available
+    pub fn try_acquire(&mut self, endpoint: Endpoint) -> Result<(), Duration> {
+        self.try_acquire_at(endpoint, Instant::now())
+    }
+
+    /// Take a call from `endpoint`'s quota, sleeping the current thread until one is available
+    ///
+    /// This never returns if `endpoint`'s limit allows no calls.
+    pub fn acquire(&mut self, endpoint: Endpoint) {
+        while let Err(wait) = self.try_acquire(endpoint) {
+            std::thread::sleep(wait);
+        }
+    }
+
+    fn try_acquire_at(&mut self, endpoint: Endpoint, now: Instant) -> Result<(), Duration> {
+        let Some(limit) = self.plan.limits.get(&endpoint) else {
+            return Ok(());
+        };
+        if limit.calls == 0 {
+            return Err(Duration::MAX);
+        }
+        if limit.period.is_zero() {
+            return Ok(());
+        }
+        let capacity = f64::from(limit.calls);
+        let rate = capacity / limit.period.as_secs_f64();
+
+        let bucket = self.buckets.entry(endpoint).or_insert(Bucket {
+            tokens: capacity,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * rate).min(capacity);
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let wait =
+                Duration::try_from_secs_f64((1.0 - bucket.tokens) / rate).unwrap_or(Duration::MAX);
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?endpoint, ?wait, "rate limited");
+            Err(wait)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket() {
+        let plan = Plan::default().with(Endpoint::CurrentWeather, Limit::per_minute(2));
+        let mut limiter = RateLimiter::new(plan);
+        let start = Instant::now();
+
+        assert!(limiter
+            .try_acquire_at(Endpoint::CurrentWeather, start)
+            .is_ok());
+        assert!(limiter
+            .try_acquire_at(Endpoint::CurrentWeather, start)
+            .is_ok());
+        let wait = limiter
+            .try_acquire_at(Endpoint::CurrentWeather, start)
+            .unwrap_err();
+        assert_eq!(30, wait.as_secs());
+
+        let later = start + Duration::from_secs(30);
+        assert!(limiter
+            .try_acquire_at(Endpoint::CurrentWeather, later)
+            .is_ok());
+        assert!(limiter
+            .try_acquire_at(Endpoint::CurrentWeather, later)
+            .is_err());
+
+        // Endpoints without a limit are never limited
+        for _ in 0..10 {
+            assert!(limiter.try_acquire_at(Endpoint::OneCall, start).is_ok());
+        }
+    }
+
+    #[test]
+    fn degenerate_limits() {
+        let plan = Plan::free()
+            .with(Endpoint::OneCall, Limit::per_day(0))
+            .with(
+                Endpoint::CurrentWeather,
+                Limit {
+                    calls: 1,
+                    period: Duration::ZERO,
+                },
+            )
+            .with(
+                Endpoint::Forecast,
+                Limit {
+                    calls: 1,
+                    period: Duration::MAX,
+                },
+            );
+        let mut limiter = RateLimiter::new(plan);
+        let now = Instant::now();
+
+        assert_eq!(
+            Err(Duration::MAX),
+            limiter.try_acquire_at(Endpoint::OneCall, now)
+        );
+        for _ in 0..10 {
+            assert!(limiter
+                .try_acquire_at(Endpoint::CurrentWeather, now)
+                .is_ok());
+        }
+        assert!(limiter.try_acquire_at(Endpoint::Forecast, now).is_ok());
+        assert_eq!(
+            Err(Duration::MAX),
+            limiter.try_acquire_at(Endpoint::Forecast, now)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Adaptive polling intervals based on how changeable the weather is.
+//!
+//! Polling often is only worth the API calls when conditions are changing. [`RefreshPolicy`]
+//! looks at a response and picks a shorter interval when there are active alerts, precipitation
+//! is imminent, or pressure is falling quickly, and a longer one otherwise.
+
+use crate::Weather;
+use jiff::{Span, Zoned};
+use std::time::Duration;
+
+/// How changeable the weather is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Volatility {
+    Stable,
+
+    /// Precipitation is likely within a few hours, or pressure is falling quickly
+    Unsettled,
+
+    /// An alert is active, or precipitation is expected within the hour
+    Volatile,
+}
+
+/// Polling interval for each level of [`Volatility`]
+#[derive(Debug, Clone, Copy)]
+pub struct RefreshPolicy {
+    pub stable: Duration,
+    pub unsettled: Duration,
+    pub volatile: Duration,
+
+    /// Probability of precipitation in the next 3 hours at which conditions are unsettled
+    pub pop_threshold: f64,
+
+    /// Pressure drop over the next 3 hours at which conditions are unsettled, hPa
+    pub pressure_drop: u16,
+}
+
+impl Default for RefreshPolicy {
+    fn default() -> Self {
+        Self {
+            stable: Duration::from_secs(2 * 60 * 60),
+            unsettled: Duration::from_secs(30 * 60),
+            volatile: Duration::from_secs(10 * 60),
+            pop_threshold: 0.5,
+            pressure_drop: 3,
+        }
+    }
+}
+
+impl RefreshPolicy {
+    /// How long to wait before fetching again after receiving `weather`
+    pub fn interval(&self, weather: &Weather) -> Duration {
+        match self.volatility(weather) {
+            Volatility::Stable => self.stable,
+            Volatility::Unsettled => self.unsettled,
+            Volatility::Volatile => self.volatile,
+        }
+    }
+
+    /// Classify `weather` as of its current time, or its first hour if there's no current data
+    pub fn volatility(&self, weather: &Weather) -> Volatility {
+        let Some(now) = weather
+            .current
+            .as_ref()
+            .map(|x| &x.dt)
+            .or_else(|| weather.hourly.as_ref()?.first().map(|x| &x.dt))
+        else {
+            return Volatility::Stable;
+        };
+        let within = |dt: &Zoned, hours: i64| {
+            dt >= now && *dt <= now.saturating_add(Span::new().hours(hours))
+        };
+
+        let alert_active = weather
+            .alerts
+            .iter()
+            .flatten()
+            .any(|x| x.start <= *now && *now < x.end);
+        let precipitation_soon = weather
+            .minutely
+            .iter()
+            .flatten()
+            .any(|x| within(&x.dt, 1) && x.precipitation > 0.0);
+        if alert_active || precipitation_soon {
+            return Volatility::Volatile;
+        }
+
+        let next_hours: Vec<_> = weather
+            .hourly
+            .iter()
+            .flatten()
+            .filter(|x| within(&x.dt, 3))
+            .collect();
+        let precipitation_likely = next_hours
+            .iter()
+            .any(|x| x.pop.is_some_and(|x| x >= self.pop_threshold));
+        let pressure_falling = match (next_hours.first(), next_hours.last()) {
+            (Some(first), Some(last)) => {
+                first.pressure.saturating_sub(last.pressure) >= self.pressure_drop
+            }
+            _ => false,
+        };
+        if precipitation_likely || pressure_falling {
+            Volatility::Unsettled
+        } else {
+            Volatility::Stable
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pick_interval() {
+        let mut weather = crate::fixtures::full();
+        let policy = RefreshPolicy::default();
+        assert_eq!(Volatility::Volatile, policy.volatility(&weather));
+
+        weather.alerts = None;
+        weather.minutely = None;
+        assert_eq!(Volatility::Unsettled, policy.volatility(&weather));
+        assert_eq!(Duration::from_secs(30 * 60), policy.interval(&weather));
+
+        weather.hourly = None;
+        assert_eq!(Volatility::Stable, policy.volatility(&weather));
+    }
+}
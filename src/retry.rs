@@ -0,0 +1,183 @@
+//! When and how long to wait before retrying a failed call.
+//!
+//! A [`RetryPolicy`] retries rate limited calls (429), server errors (5xx), and connection
+//! errors with jittered exponential backoff, honours the server's `Retry-After` header, and gives
+//! up once a total time budget is spent. Ask [`RetryPolicy::next_delay`] after each failure.
+
+use jiff::{fmt::rfc2822, Timestamp};
+use std::{
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hasher},
+    time::Duration,
+};
+
+/// Longest wait [`parse_retry_after`] returns. OWM's quotas reset daily, so a longer one is a
+/// broken header rather than advice.
+pub const MAX_RETRY_AFTER: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// Retry limits and backoff
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+
+    /// Delay before the first retry. It doubles with each retry after that.
+    pub base_delay: Duration,
+
+    /// Longest delay between two attempts, not counting `Retry-After`
+    pub max_delay: Duration,
+
+    /// Time after the first attempt to stop retrying, including the delay before the next retry
+    pub max_elapsed: Duration,
+
+    /// Fraction of each backoff delay, from 0 to 1, that's randomized to spread out retries
+    pub jitter: f64,
+}
+
+impl Default for RetryPolicy {
+    /// Three retries starting at half a second, at most 30 seconds apart and within a minute,
+    /// with half of each delay randomized
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_elapsed: Duration::from_secs(60),
+            jitter: 0.5,
+        }
+    }
+}
+
+/// Why a call failed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Failure {
+    /// HTTP status of the response
+    Status(u16),
+
+    /// No response, because the connection failed or timed out
+    Connection,
+}
+
+impl Failure {
+    /// Whether trying again could succeed
+    pub fn is_retryable(self) -> bool {
+        match self {
+            Self::Status(status) => status == 429 || (500..600).contains(&status),
+            Self::Connection => true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// How long to wait before retrying after the `attempt`th failure (starting at 1), or `None`
+    /// to give up. `elapsed` is the time since the first attempt, and `retry_after` the parsed
+    /// `Retry-After` header of the failed response, if any.
+    pub fn next_delay(
+        &self,
+        failure: Failure,
+        attempt: u32,
+        elapsed: Duration,
+        retry_after: Option<Duration>,
+    ) -> Option<Duration> {
+        if !failure.is_retryable() || attempt > self.max_retries {
+            #[cfg(feature = "tracing")]
+            tracing::debug!(?failure, attempt, "not retrying");
+            return None;
+        }
+
+        let delay = retry_after.unwrap_or_else(|| self.backoff(attempt, random()));
+        let delay = elapsed
+            .checked_add(delay)
+            .is_some_and(|x| x <= self.max_elapsed)
+            .then_some(delay);
+        #[cfg(feature = "tracing")]
+        tracing::debug!(?failure, attempt, ?delay, ?retry_after, "retry");
+        delay
+    }
+
+    /// Backoff delay after the `attempt`th failure, with `random` from 0 to 1 choosing the jitter
+    fn backoff(&self, attempt: u32, random: f64) -> Duration {
+        let exponential = self
+            .base_delay
+            .saturating_mul(2_u32.saturating_pow(attempt.saturating_sub(1)));
+        let delay = exponential.min(self.max_delay);
+        delay.mul_f64(1.0 - self.jitter.clamp(0.0, 1.0) * random)
+    }
+}
+
+/// Parse a `Retry-After` header, either a number of seconds or an HTTP date, into the time to wait
+/// from `now`. Dates in the past mean no wait, and waits longer than [`MAX_RETRY_AFTER`] are cut
+/// down to it.
+pub fn parse_retry_after(value: &str, now: Timestamp) -> Option<Duration> {
+    let value = value.trim();
+    let seconds = match value.parse::<u64>() {
+        Ok(seconds) => seconds,
+        Err(_) => {
+            let date = rfc2822::parse(value).ok()?.timestamp();
+            (date.as_second() - now.as_second()).max(0) as u64
+        }
+    };
+    Some(Duration::from_secs(seconds).min(MAX_RETRY_AFTER))
+}
+
+/// A number from 0 to 1 that's good enough for jitter, without a dependency on `rand`
+fn random() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1_u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn retry_delays() {
+        let policy = RetryPolicy {
+            jitter: 0.0,
+            ..RetryPolicy::default()
+        };
+        let delay = |failure, attempt, elapsed, retry_after| {
+            policy.next_delay(failure, attempt, Duration::from_secs(elapsed), retry_after)
+        };
+        assert_eq!(
+            Some(Duration::from_millis(500)),
+            delay(Failure::Status(503), 1, 0, None)
+        );
+        assert_eq!(
+            Some(Duration::from_secs(2)),
+            delay(Failure::Connection, 3, 0, None)
+        );
+        assert_eq!(None, delay(Failure::Connection, 4, 0, None));
+        assert_eq!(None, delay(Failure::Status(401), 1, 0, None));
+        assert_eq!(
+            Some(Duration::from_secs(45)),
+            delay(Failure::Status(429), 1, 10, Some(Duration::from_secs(45)))
+        );
+        assert_eq!(
+            None,
+            delay(Failure::Status(429), 1, 20, Some(Duration::from_secs(45)))
+        );
+
+        let jittered = RetryPolicy::default().backoff(20, 1.0);
+        assert_eq!(Duration::from_secs(15), jittered);
+
+        let now: Timestamp = "2024-07-11T12:00:00Z".parse().unwrap();
+        assert_eq!(
+            Some(Duration::from_secs(120)),
+            parse_retry_after("120", now)
+        );
+        assert_eq!(
+            Some(Duration::from_secs(90)),
+            parse_retry_after("Thu, 11 Jul 2024 12:01:30 GMT", now)
+        );
+        assert_eq!(None, parse_retry_after("soon", now));
+
+        let forever = parse_retry_after("18446744073709551615", now);
+        assert_eq!(Some(MAX_RETRY_AFTER), forever);
+        assert_eq!(None, delay(Failure::Status(429), 1, 10, forever));
+        assert_eq!(
+            None,
+            policy.next_delay(Failure::Status(429), 1, Duration::MAX, Some(Duration::MAX))
+        );
+    }
+}
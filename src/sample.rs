@@ -0,0 +1,116 @@
+//! The surface shared by current, hourly, daily, and interpolated entries.
+
+use crate::{interpolate::Interpolated, Current, Daily, Hourly, WeatherElement};
+use jiff::Zoned;
+
+/// A single point in time of a [`Weather`](crate::Weather) response, so rendering code can take
+/// any kind of entry. Values are in the units of the request.
+///
+/// Daily entries have a temperature for each part of the day. They're represented by their day
+/// temperature.
+pub trait WeatherSample {
+    /// Time of the entry
+    fn dt(&self) -> &Zoned;
+
+    fn temp(&self) -> f64;
+    fn feels_like(&self) -> f64;
+    fn pressure(&self) -> u16;
+    fn humidity(&self) -> u8;
+    fn dew_point(&self) -> f64;
+    fn clouds(&self) -> u8;
+    /// UV index, which some historical hourly entries don't have
+    fn uvi(&self) -> Option<f64>;
+    fn wind_speed(&self) -> f64;
+    fn wind_gust(&self) -> Option<f64>;
+    fn wind_deg(&self) -> u16;
+
+    /// Weather conditions, primary first
+    fn conditions(&self) -> &[WeatherElement];
+}
+
+macro_rules! impl_sample {
+    ($t:ty $(, $part:ident)?) => {
+        impl WeatherSample for $t {
+            fn dt(&self) -> &Zoned {
+                &self.dt
+            }
+
+            fn temp(&self) -> f64 {
+                self.temp$(.$part)?
+            }
+
+            fn feels_like(&self) -> f64 {
+                self.feels_like$(.$part)?
+            }
+
+            fn pressure(&self) -> u16 {
+                self.pressure
+            }
+
+            fn humidity(&self) -> u8 {
+                self.humidity
+            }
+
+            fn dew_point(&self) -> f64 {
+                self.dew_point
+            }
+
+            fn clouds(&self) -> u8 {
+                self.clouds
+            }
+
+            fn uvi(&self) -> Option<f64> {
+                self.uvi.into()
+            }
+
+            fn wind_speed(&self) -> f64 {
+                self.wind_speed
+            }
+
+            fn wind_gust(&self) -> Option<f64> {
+                self.wind_gust
+            }
+
+            fn wind_deg(&self) -> u16 {
+                self.wind_deg
+            }
+
+            fn conditions(&self) -> &[WeatherElement] {
+                &self.weather
+            }
+        }
+    };
+}
+
+impl_sample!(Current);
+impl_sample!(Hourly);
+impl_sample!(Daily, day);
+impl_sample!(Interpolated);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn warmest(samples: &[impl WeatherSample]) -> f64 {
+        samples.iter().map(|x| x.temp()).fold(f64::MIN, f64::max)
+    }
+
+    #[test]
+    fn generic_samples() {
+        let weather = crate::fixtures::full();
+        let current = weather.current.unwrap();
+        let hourly = weather.hourly.unwrap();
+        let daily = weather.daily.unwrap();
+
+        assert_eq!(current.temp, WeatherSample::temp(&current));
+        assert_eq!(
+            hourly.iter().map(|x| x.temp).fold(f64::MIN, f64::max),
+            warmest(&hourly)
+        );
+        assert_eq!(daily[0].temp.day, daily[0].temp());
+        assert_eq!(
+            &daily[0].weather[0].description,
+            &daily[0].conditions()[0].description
+        );
+    }
+}
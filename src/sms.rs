@@ -0,0 +1,232 @@
+//! Compact daily forecasts that fit in a single SMS or satellite message.
+//!
+//! Each day is encoded as its two letter weekday at the location followed by one token per
+//! field, made of the field's abbreviation and its value. Days are separated by `;`:
+//!
+//! ```text
+//! Su H28 L17 P81 R3.4;Mo H27 L16 P0
+//! ```
+//!
+//! Numbers are formatted with [`SmsFormat::policy`]. By default, temperatures and wind are
+//! rounded to whole units, precipitation volumes to one decimal, and the probability of
+//! precipitation is given as a percentage.
+
+use crate::{
+    format::FormatPolicy,
+    projection::{daily_value, Field},
+    Weather,
+};
+use jiff::civil::Weekday;
+use std::fmt;
+
+/// Maximum length of a single SMS message
+pub const SMS_LENGTH: usize = 160;
+
+/// Which fields to encode, how to abbreviate them, and how long the message may be
+#[derive(Debug, Clone)]
+pub struct SmsFormat {
+    /// Fields to encode, in order, with their abbreviation
+    pub fields: Vec<(Field, String)>,
+
+    /// Maximum length of the encoded message. Days that don't fit are left out.
+    pub max_len: usize,
+
+    /// Decimal places, rounding, and decimal separator of the values. Unit labels are never
+    /// included.
+    pub policy: FormatPolicy,
+}
+
+impl Default for SmsFormat {
+    fn default() -> Self {
+        Self {
+            fields: [
+                (Field::TempMax, "H"),
+                (Field::TempMin, "L"),
+                (Field::Pop, "P"),
+                (Field::Rain, "R"),
+                (Field::Snow, "S"),
+                (Field::WindGust, "G"),
+            ]
+            .into_iter()
+            .map(|(field, abbr)| (field, abbr.to_string()))
+            .collect(),
+            max_len: SMS_LENGTH,
+            policy: FormatPolicy::default(),
+        }
+    }
+}
+
+/// A single decoded day
+#[derive(Debug, PartialEq)]
+pub struct SmsDay {
+    /// Two letter weekday
+    pub label: String,
+
+    /// Decoded field values, in the same units as [`Projection`](crate::projection::Projection)
+    pub values: Vec<(Field, f64)>,
+}
+
+/// A token that couldn't be decoded
+#[derive(Debug)]
+pub struct DecodeError {
+    pub token: String,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid SMS token: {:?}", self.token)
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl SmsFormat {
+    /// Encode as many of `weather`'s daily forecasts as fit in [`max_len`](Self::max_len)
+    pub fn encode(&self, weather: &Weather) -> String {
+        let local = weather.time_zone();
+        let mut message = String::new();
+        for day in weather.daily.iter().flatten() {
+            let local = day.dt.with_time_zone(local.clone());
+            let mut encoded = weekday(local.weekday()).to_string();
+            for (field, abbr) in &self.fields {
+                if let Some(value) = daily_value(day, *field) {
+                    encoded.push(' ');
+                    encoded.push_str(abbr);
+                    encoded.push_str(&self.format_value(*field, value));
+                }
+            }
+
+            let separator = usize::from(!message.is_empty());
+            if message.len() + separator + encoded.len() > self.max_len {
+                break;
+            }
+            if separator > 0 {
+                message.push(';');
+            }
+            message.push_str(&encoded);
+        }
+        message
+    }
+
+    /// Decode a message produced by [`encode`](Self::encode) with the same format
+    pub fn decode(&self, message: &str) -> Result<Vec<SmsDay>, DecodeError> {
+        message
+            .split(';')
+            .map(|day| {
+                let mut tokens = day.split_whitespace();
+                let label = tokens.next().unwrap_or_default().to_string();
+                let values = tokens
+                    .map(|token| self.decode_token(token))
+                    .collect::<Result<_, _>>()?;
+                Ok(SmsDay { label, values })
+            })
+            .collect()
+    }
+
+    fn decode_token(&self, token: &str) -> Result<(Field, f64), DecodeError> {
+        let invalid = || DecodeError {
+            token: token.to_string(),
+        };
+
+        // Prefer the longest matching abbreviation, in case one is a prefix of another
+        let (field, abbr) = self
+            .fields
+            .iter()
+            .filter(|(_, abbr)| token.starts_with(abbr.as_str()))
+            .max_by_key(|(_, abbr)| abbr.len())
+            .ok_or_else(invalid)?;
+        let value: f64 = token[abbr.len()..]
+            .replace(self.policy.decimal_separator, ".")
+            .parse()
+            .map_err(|_| invalid())?;
+
+        Ok(match field {
+            Field::Pop => (*field, value / 100.0),
+            _ => (*field, value),
+        })
+    }
+
+    fn format_value(&self, field: Field, value: f64) -> String {
+        let policy = &self.policy;
+        let decimals = match field {
+            Field::Temp | Field::TempMin | Field::TempMax | Field::FeelsLike | Field::DewPoint => {
+                policy.temperature_decimals
+            }
+            Field::WindSpeed | Field::WindGust => policy.wind_speed_decimals,
+            Field::Rain | Field::Snow => policy.precipitation_decimals,
+            Field::Pop => return policy.number(value * 100.0, policy.percentage_decimals),
+            _ => 0,
+        };
+        policy.number(value, decimals)
+    }
+}
+
+fn weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Monday => "Mo",
+        Weekday::Tuesday => "Tu",
+        Weekday::Wednesday => "We",
+        Weekday::Thursday => "Th",
+        Weekday::Friday => "Fr",
+        Weekday::Saturday => "Sa",
+        Weekday::Sunday => "Su",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_and_decode() {
+        let mut weather = crate::fixtures::full();
+        let format = SmsFormat::default();
+
+        let message = format.encode(&weather);
+        assert_eq!(
+            "Su H28 L17 P81 R3.4 G14;Mo H27 L16 P0 G8;Tu H25 L17 P74 R1.9 G12",
+            message
+        );
+
+        let days = format.decode(&message).unwrap();
+        assert_eq!("Mo", days[1].label);
+        assert_eq!(
+            vec![
+                (Field::TempMax, 27.0),
+                (Field::TempMin, 16.0),
+                (Field::Pop, 0.0),
+                (Field::WindGust, 8.0)
+            ],
+            days[1].values
+        );
+
+        let short = SmsFormat {
+            max_len: 30,
+            ..SmsFormat::default()
+        };
+        assert_eq!("Su H28 L17 P81 R3.4 G14", short.encode(&weather));
+
+        let european = SmsFormat {
+            policy: FormatPolicy {
+                decimal_separator: ',',
+                ..FormatPolicy::default()
+            },
+            ..SmsFormat::default()
+        };
+        weather.daily.as_mut().unwrap().truncate(1);
+        let message = european.encode(&weather);
+        assert_eq!("Su H28 L17 P81 R3,4 G14", message);
+        assert_eq!(
+            (Field::Rain, 3.4),
+            european.decode(&message).unwrap()[0].values[3]
+        );
+    }
+
+    #[test]
+    fn local_weekdays() {
+        // Local noon in Auckland in summer is 23:00 UTC the day before
+        let weather = crate::fixtures::auckland();
+        let message = SmsFormat::default().encode(&weather);
+        assert_eq!("Mo H24 L17 P20 G9;Tu H23 L17 P78 R2.1 G9", message);
+    }
+}
@@ -0,0 +1,77 @@
+//! Extension point for attaching third-party data to daily forecasts.
+//!
+//! OWM doesn't provide data such as pollen counts or allergy indices, but it's often shown
+//! alongside the forecast. Implement [`DailySupplement`] for such a source (or use a closure) to
+//! pair its data with each [`Daily`] entry.
+
+use crate::Daily;
+
+/// A source of supplemental data for daily forecasts
+pub trait DailySupplement {
+    type Data;
+
+    /// Data for the same day as `day`, if the source has any
+    fn for_day(&self, day: &Daily) -> Option<Self::Data>;
+}
+
+impl<F, T> DailySupplement for F
+where
+    F: Fn(&Daily) -> Option<T>,
+{
+    type Data = T;
+
+    fn for_day(&self, day: &Daily) -> Option<T> {
+        self(day)
+    }
+}
+
+/// A daily forecast paired with supplemental data
+#[derive(Debug)]
+pub struct Supplemented<'a, T> {
+    pub day: &'a Daily,
+    pub supplement: Option<T>,
+}
+
+/// Pair each of `days` with data from `source`
+pub fn supplement_daily<'a, S>(days: &'a [Daily], source: &S) -> Vec<Supplemented<'a, S::Data>>
+where
+    S: DailySupplement,
+{
+    days.iter()
+        .map(|day| Supplemented {
+            day,
+            supplement: source.for_day(day),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jiff::civil::date;
+
+    struct Pollen;
+
+    impl DailySupplement for Pollen {
+        type Data = u8;
+
+        fn for_day(&self, day: &Daily) -> Option<u8> {
+            (day.dt.date() == date(2024, 7, 22)).then_some(4)
+        }
+    }
+
+    #[test]
+    fn supplement_days() {
+        let weather = crate::fixtures::full();
+        let daily = weather.daily.unwrap();
+
+        let days = supplement_daily(&daily, &Pollen);
+        assert_eq!(
+            vec![None, Some(4), None],
+            days.iter().map(|x| x.supplement).collect::<Vec<_>>()
+        );
+
+        let days = supplement_daily(&daily, &|day: &Daily| Some(day.uvi));
+        assert_eq!(Some(8.1), days[1].supplement);
+    }
+}
@@ -0,0 +1,138 @@
+//! Sparklines and bar charts of hourly forecasts for terminals.
+//!
+//! Each value is drawn as one of the block characters `▁▂▃▄▅▆▇█`, one character per hour:
+//!
+//! ```
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../fixtures/onecall.json"))?;
+//! # let hourly = weather.hourly.unwrap();
+//! use owm_rs::{term, Units};
+//!
+//! println!("{}", term::temperature(&hourly, Units::Metric, false));
+//! println!("{}", term::precipitation(&hourly, false));
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+//!
+//! With `color`, values are colored with ANSI escape codes: temperatures from blue for freezing
+//! to red for hot, and precipitation in blue.
+
+use crate::{Hourly, Units};
+
+const BLOCKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Hourly precipitation drawn as a full bar, mm. Heavy rain starts at about 4 mm an hour.
+pub const PRECIPITATION_SCALE: f64 = 4.0;
+
+const RESET: &str = "\x1b[0m";
+const BLUE: u8 = 34;
+
+/// Sparkline of `values`, scaled from their minimum to their maximum. A constant series is drawn
+/// at mid height.
+pub fn sparkline(values: &[f64]) -> String {
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    values
+        .iter()
+        .map(|&x| {
+            if max > min {
+                block((x - min) / (max - min))
+            } else {
+                BLOCKS[BLOCKS.len() / 2 - 1]
+            }
+        })
+        .collect()
+}
+
+/// Bar chart of `values` from zero to `scale`. Zero is drawn as a space, and values above
+/// `scale` as a full bar.
+pub fn bars(values: &[f64], scale: f64) -> String {
+    values
+        .iter()
+        .map(|&x| if x > 0.0 { block(x / scale) } else { ' ' })
+        .collect()
+}
+
+/// Sparkline of hourly temperatures, in the `units` of the request
+pub fn temperature(hourly: &[Hourly], units: Units, color: bool) -> String {
+    let temps: Vec<f64> = hourly.iter().map(|x| x.temp).collect();
+    let chart = sparkline(&temps);
+    if !color {
+        return chart;
+    }
+    let codes = temps
+        .iter()
+        .map(|&x| temperature_color(units.to_celsius(x)));
+    paint(&chart, codes)
+}
+
+/// Bar chart of hourly rain and snow volumes, scaled to [`PRECIPITATION_SCALE`]
+pub fn precipitation(hourly: &[Hourly], color: bool) -> String {
+    let volumes: Vec<f64> = hourly
+        .iter()
+        .map(|x| x.total_precipitation().millimetres())
+        .collect();
+    let chart = bars(&volumes, PRECIPITATION_SCALE);
+    if color {
+        paint(&chart, volumes.iter().map(|_| BLUE))
+    } else {
+        chart
+    }
+}
+
+/// Block for a fraction from 0 to 1
+fn block(fraction: f64) -> char {
+    let index = (fraction * (BLOCKS.len() - 1) as f64).round();
+    BLOCKS[index.clamp(0.0, (BLOCKS.len() - 1) as f64) as usize]
+}
+
+/// ANSI foreground color code for a temperature in Celsius
+fn temperature_color(celsius: f64) -> u8 {
+    match celsius {
+        x if x < -10.0 => 94,
+        x if x < 0.0 => BLUE,
+        x if x < 10.0 => 36,
+        x if x < 20.0 => 32,
+        x if x < 30.0 => 33,
+        _ => 31,
+    }
+}
+
+/// Color each character of `chart` with the code at the same position, writing a code only
+/// when it changes
+fn paint(chart: &str, codes: impl Iterator<Item = u8>) -> String {
+    let mut painted = String::new();
+    let mut current = None;
+    for (c, code) in chart.chars().zip(codes) {
+        if current != Some(code) {
+            painted.push_str(&format!("\x1b[{code}m"));
+            current = Some(code);
+        }
+        painted.push(c);
+    }
+    if current.is_some() {
+        painted.push_str(RESET);
+    }
+    painted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn charts() {
+        assert_eq!("▁▃▅█", sparkline(&[0.0, 1.0, 2.0, 3.5]));
+        assert_eq!("▄▄", sparkline(&[5.0, 5.0]));
+        assert_eq!(" ▃█", bars(&[0.0, 1.0, 9.0], PRECIPITATION_SCALE));
+        assert_eq!(
+            "\x1b[34m▁\x1b[36m▅█\x1b[0m",
+            paint("▁▅█", [-5.0, 2.0, 8.0].map(temperature_color).into_iter())
+        );
+
+        let weather = crate::fixtures::full();
+        let hourly = weather.hourly.unwrap();
+        assert_eq!(
+            hourly.len(),
+            temperature(&hourly, Units::Metric, false).chars().count()
+        );
+    }
+}
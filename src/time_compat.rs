@@ -0,0 +1,92 @@
+//! Conversions between the models' [`jiff`] times and the [`time`](https://docs.rs/time) crate.
+//!
+//! As with [`chrono_compat`](crate::chrono_compat), the models keep using [`Zoned`]; convert at
+//! the boundary instead:
+//!
+//! ```
+//! use owm_rs::time_compat::ToOffsetDateTime;
+//! # let weather: owm_rs::Weather = serde_json::from_str(include_str!("../fixtures/onecall.json"))?;
+//! let dt: time::OffsetDateTime = weather.current.unwrap().dt.to_offset_date_time();
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use jiff::{tz, Timestamp, Zoned};
+use time::{OffsetDateTime, UtcOffset};
+
+/// Conversion of a jiff time to an [`OffsetDateTime`]
+pub trait ToOffsetDateTime {
+    /// The same instant, keeping the UTC offset
+    fn to_offset_date_time(&self) -> OffsetDateTime;
+}
+
+impl ToOffsetDateTime for Zoned {
+    fn to_offset_date_time(&self) -> OffsetDateTime {
+        let offset = UtcOffset::from_whole_seconds(self.offset().seconds())
+            .expect("jiff offsets are less than a day");
+        self.timestamp().to_offset_date_time().to_offset(offset)
+    }
+}
+
+impl ToOffsetDateTime for Timestamp {
+    fn to_offset_date_time(&self) -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp_nanos(self.as_nanosecond())
+            .expect("jiff timestamps are within time's range")
+    }
+}
+
+/// The same instant as a [`Zoned`] with `dt`'s UTC offset, or `None` if it's outside jiff's
+/// range
+pub fn to_zoned(dt: OffsetDateTime) -> Option<Zoned> {
+    let offset = tz::Offset::from_seconds(dt.offset().whole_seconds()).ok()?;
+    let timestamp = Timestamp::from_nanosecond(dt.unix_timestamp_nanos()).ok()?;
+    Some(timestamp.to_zoned(tz::TimeZone::fixed(offset)))
+}
+
+/// Serialize an [`OffsetDateTime`] as a unix timestamp in seconds, the way OWM sends times
+///
+/// For fields of your own types that hold times taken from the models:
+/// `#[serde(with = "owm_rs::time_compat::ts_seconds")]`. Deserialized times are in UTC.
+pub mod ts_seconds {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+    use time::OffsetDateTime;
+
+    pub fn serialize<S>(dt: &OffsetDateTime, s: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        s.serialize_i64(dt.unix_timestamp())
+    }
+
+    pub fn deserialize<'de, D>(d: D) -> Result<OffsetDateTime, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let seconds = i64::deserialize(d)?;
+        OffsetDateTime::from_unix_timestamp(seconds)
+            .map_err(|_| de::Error::custom(format!("invalid timestamp: {seconds}")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let zoned: Zoned = "2024-07-01T06:30:15.5-05:00[America/Winnipeg]"
+            .parse()
+            .unwrap();
+        let dt = zoned.to_offset_date_time();
+        assert_eq!(6, dt.hour());
+        assert_eq!(-5, dt.offset().whole_hours());
+        assert_eq!(500, dt.millisecond());
+        assert_eq!(zoned.timestamp(), to_zoned(dt).unwrap().timestamp());
+
+        #[derive(serde::Deserialize, serde::Serialize)]
+        struct Sample(#[serde(with = "ts_seconds")] OffsetDateTime);
+
+        let x: Sample = serde_json::from_str("1719833415").unwrap();
+        assert_eq!(zoned.timestamp().as_second(), x.0.unix_timestamp());
+        assert_eq!("1719833415", serde_json::to_string(&x).unwrap());
+    }
+}
@@ -0,0 +1,241 @@
+//! [ratatui](https://docs.rs/ratatui) widgets for terminal dashboards.
+//!
+//! Each widget takes a [`Weather`] and renders a part of it, with times in the response's local
+//! offset:
+//!
+//! - [`HourlyChart`]: a line chart of the hourly temperatures
+//! - [`DailyTable`]: a table of the daily forecasts
+//! - [`AlertBanner`]: a banner with one line per alert, rendering nothing without alerts
+
+use crate::{format::FormatPolicy, units::Temperature, Units, Weather};
+use jiff::Zoned;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    symbols::Marker,
+    text::Line,
+    widgets::{Axis, Block, Chart, Dataset, GraphType, Paragraph, Row, Table, Widget, Wrap},
+};
+
+fn local(weather: &Weather, dt: &Zoned) -> Zoned {
+    dt.with_time_zone(weather.time_zone())
+}
+
+/// Line chart of the hourly temperatures
+#[derive(Debug, Clone)]
+pub struct HourlyChart<'a> {
+    weather: &'a Weather,
+    units: Units,
+    policy: FormatPolicy,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> HourlyChart<'a> {
+    /// Chart of `weather`'s hourly temperatures, in the `units` of the request
+    pub fn new(weather: &'a Weather, units: Units) -> Self {
+        Self {
+            weather,
+            units,
+            policy: FormatPolicy::default(),
+            block: None,
+        }
+    }
+
+    /// Format the axis labels with `policy`
+    pub fn policy(mut self, policy: FormatPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Surround the chart with `block`
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl Widget for HourlyChart<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let hourly = self.weather.hourly.as_deref().unwrap_or_default();
+        let data: Vec<(f64, f64)> = hourly
+            .iter()
+            .enumerate()
+            .map(|(i, x)| (i as f64, x.temp))
+            .collect();
+        let min = data.iter().map(|x| x.1).fold(f64::INFINITY, f64::min);
+        let max = data.iter().map(|x| x.1).fold(f64::NEG_INFINITY, f64::max);
+        let (min, max) = if min <= max { (min, max) } else { (0.0, 0.0) };
+
+        let temperature = |x| self.policy.temperature(Temperature::new(x, self.units));
+        let time = |i: usize| {
+            hourly.get(i).map_or_else(String::new, |x| {
+                local(self.weather, &x.dt).strftime("%H:%M").to_string()
+            })
+        };
+        let last = hourly.len().saturating_sub(1);
+
+        let dataset = Dataset::default()
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::new().fg(Color::Yellow))
+            .data(&data);
+        let mut chart = Chart::new(vec![dataset])
+            .x_axis(Axis::default().bounds([0.0, last as f64]).labels([
+                time(0),
+                time(last / 2),
+                time(last),
+            ]))
+            .y_axis(
+                Axis::default()
+                    .bounds([min.floor(), max.ceil()])
+                    .labels([temperature(min), temperature(max)]),
+            );
+        if let Some(block) = self.block {
+            chart = chart.block(block);
+        }
+        chart.render(area, buf);
+    }
+}
+
+/// Table of the daily forecasts: weekday, high, low, conditions, and probability of
+/// precipitation
+#[derive(Debug, Clone)]
+pub struct DailyTable<'a> {
+    weather: &'a Weather,
+    units: Units,
+    policy: FormatPolicy,
+    block: Option<Block<'a>>,
+}
+
+impl<'a> DailyTable<'a> {
+    /// Table of `weather`'s daily forecasts, in the `units` of the request
+    pub fn new(weather: &'a Weather, units: Units) -> Self {
+        Self {
+            weather,
+            units,
+            policy: FormatPolicy::default(),
+            block: None,
+        }
+    }
+
+    /// Format the values with `policy`
+    pub fn policy(mut self, policy: FormatPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Surround the table with `block`
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+}
+
+impl Widget for DailyTable<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let temperature = |x| self.policy.temperature(Temperature::new(x, self.units));
+        let rows = self.weather.daily.iter().flatten().map(|x| {
+            Row::new([
+                local(self.weather, &x.dt).strftime("%a").to_string(),
+                temperature(x.temp.max),
+                temperature(x.temp.min),
+                x.weather
+                    .first()
+                    .map_or_else(String::new, |x| x.description.clone()),
+                x.pop
+                    .map_or_else(String::new, |x| self.policy.percentage(x)),
+            ])
+        });
+        let widths = [
+            Constraint::Length(3),
+            Constraint::Length(6),
+            Constraint::Length(6),
+            Constraint::Fill(1),
+            Constraint::Length(5),
+        ];
+        let mut table = Table::new(rows, widths)
+            .header(Row::new(["Day", "High", "Low", "Conditions", "Pop"]).bold());
+        if let Some(block) = self.block {
+            table = table.block(block);
+        }
+        table.render(area, buf);
+    }
+}
+
+/// One line per alert, on a red background. Renders nothing when there are no alerts.
+#[derive(Debug, Clone)]
+pub struct AlertBanner<'a> {
+    weather: &'a Weather,
+}
+
+impl<'a> AlertBanner<'a> {
+    pub fn new(weather: &'a Weather) -> Self {
+        Self { weather }
+    }
+
+    /// Lines the banner needs, one per alert
+    pub fn height(&self) -> u16 {
+        self.weather.alerts.as_ref().map_or(0, |x| x.len() as u16)
+    }
+}
+
+impl Widget for AlertBanner<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let Some(alerts) = self.weather.alerts.as_ref().filter(|x| !x.is_empty()) else {
+            return;
+        };
+        let lines: Vec<Line> = alerts
+            .iter()
+            .map(|x| Line::from(format!("⚠ {x}")))
+            .collect();
+        Paragraph::new(lines)
+            .style(Style::new().fg(Color::White).bg(Color::Red).bold())
+            .wrap(Wrap { trim: true })
+            .render(area, buf);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(buf: &Buffer) -> String {
+        let width = buf.area.width as usize;
+        let symbols: Vec<&str> = buf.content().iter().map(|x| x.symbol()).collect();
+        symbols
+            .chunks(width)
+            .map(|x| x.concat().trim_end().to_owned() + "\n")
+            .collect()
+    }
+
+    #[test]
+    fn render_widgets() {
+        let weather = crate::fixtures::full();
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 4));
+        DailyTable::new(&weather, Units::Metric).render(buf.area, &mut buf);
+        let table = text(&buf);
+        assert!(table.starts_with("Day High   Low    Conditions"), "{table}");
+        assert!(table.contains("28°C   17°C   moderate rain"), "{table}");
+
+        let banner = AlertBanner::new(&weather);
+        let mut buf = Buffer::empty(Rect::new(0, 0, 80, banner.height()));
+        banner.render(buf.area, &mut buf);
+        assert!(text(&buf).contains("severe thunderstorm watch"));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 12));
+        HourlyChart::new(&weather, Units::Metric).render(buf.area, &mut buf);
+        assert!(text(&buf).contains("°C"));
+
+        let policy = FormatPolicy {
+            unit_labels: false,
+            ..FormatPolicy::default()
+        };
+        let mut buf = Buffer::empty(Rect::new(0, 0, 60, 12));
+        HourlyChart::new(&weather, Units::Metric)
+            .policy(policy)
+            .render(buf.area, &mut buf);
+        assert!(!text(&buf).contains("°C"));
+    }
+}
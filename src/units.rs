@@ -0,0 +1,307 @@
+//! Units of measurement, and quantities tagged with the units they were requested in.
+//!
+//! OWM returns bare numbers whose meaning depends on the `units` request parameter. Wrapping them
+//! in these types makes it explicit which units a value is in, and converts between them.
+
+use crate::{format::FormatPolicy, Current, Daily, Hourly, Weather};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Metres per second in one mile per hour
+const MPH: f64 = 0.447_04;
+
+/// Millimetres in one inch
+const INCH: f64 = 25.4;
+
+/// Units of measurement, as selected by the `units` request parameter
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Units {
+    /// Kelvin, metre/sec
+    #[default]
+    Standard,
+
+    /// Celsius, metre/sec
+    Metric,
+
+    /// Fahrenheit, miles/hour
+    Imperial,
+}
+
+impl Units {
+    /// Convert a temperature in these units to Celsius
+    pub fn to_celsius(self, temp: f64) -> f64 {
+        match self {
+            Self::Standard => temp - 273.15,
+            Self::Metric => temp,
+            Self::Imperial => (temp - 32.0) * 5.0 / 9.0,
+        }
+    }
+
+    /// Convert a temperature in Celsius to these units
+    pub fn from_celsius(self, temp: f64) -> f64 {
+        match self {
+            Self::Standard => temp + 273.15,
+            Self::Metric => temp,
+            Self::Imperial => temp * 9.0 / 5.0 + 32.0,
+        }
+    }
+
+    /// Convert a speed in these units to metres per second
+    pub fn to_metres_per_second(self, speed: f64) -> f64 {
+        match self {
+            Self::Standard | Self::Metric => speed,
+            Self::Imperial => speed * MPH,
+        }
+    }
+
+    /// Convert a speed in metres per second to these units
+    pub fn from_metres_per_second(self, speed: f64) -> f64 {
+        match self {
+            Self::Standard | Self::Metric => speed,
+            Self::Imperial => speed / MPH,
+        }
+    }
+
+    /// Symbol for temperatures in these units, such as `"°C"`
+    pub fn temperature_symbol(self) -> &'static str {
+        match self {
+            Self::Standard => "K",
+            Self::Metric => "°C",
+            Self::Imperial => "°F",
+        }
+    }
+
+    /// Symbol for speeds in these units, such as `"m/s"`
+    pub fn speed_symbol(self) -> &'static str {
+        match self {
+            Self::Standard | Self::Metric => "m/s",
+            Self::Imperial => "mph",
+        }
+    }
+}
+
+/// A temperature in the units of the request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Temperature {
+    pub value: f64,
+    pub units: Units,
+}
+
+impl Temperature {
+    pub fn new(value: f64, units: Units) -> Self {
+        Self { value, units }
+    }
+
+    /// Temperature in kelvin
+    pub fn kelvin(self) -> f64 {
+        Units::Standard.from_celsius(self.celsius())
+    }
+
+    /// Temperature in Celsius
+    pub fn celsius(self) -> f64 {
+        self.units.to_celsius(self.value)
+    }
+
+    /// Temperature in Fahrenheit
+    pub fn fahrenheit(self) -> f64 {
+        Units::Imperial.from_celsius(self.celsius())
+    }
+}
+
+/// A wind speed in the units of the request
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WindSpeed {
+    pub value: f64,
+    pub units: Units,
+}
+
+impl WindSpeed {
+    pub fn new(value: f64, units: Units) -> Self {
+        Self { value, units }
+    }
+
+    /// Wind speed in metres per second
+    pub fn metres_per_second(self) -> f64 {
+        self.units.to_metres_per_second(self.value)
+    }
+
+    /// Wind speed in kilometres per hour
+    pub fn kilometres_per_hour(self) -> f64 {
+        self.metres_per_second() * 3.6
+    }
+
+    /// Wind speed in miles per hour
+    pub fn miles_per_hour(self) -> f64 {
+        Units::Imperial.from_metres_per_second(self.metres_per_second())
+    }
+
+    /// Wind speed in knots
+    pub fn knots(self) -> f64 {
+        self.metres_per_second() * 3.6 / 1.852
+    }
+}
+
+/// A precipitation volume. OWM always reports these in millimetres, regardless of the units of
+/// the request.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Precipitation(pub f64);
+
+impl Precipitation {
+    /// Volume in millimetres
+    pub fn millimetres(self) -> f64 {
+        self.0
+    }
+
+    /// Volume in inches
+    pub fn inches(self) -> f64 {
+        self.0 / INCH
+    }
+}
+
+impl fmt::Display for Temperature {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let policy = FormatPolicy::default();
+        let policy = FormatPolicy {
+            temperature_decimals: f.precision().unwrap_or(policy.temperature_decimals),
+            ..policy
+        };
+        f.write_str(&policy.temperature(*self))
+    }
+}
+
+impl fmt::Display for WindSpeed {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let policy = FormatPolicy::default();
+        let policy = FormatPolicy {
+            wind_speed_decimals: f.precision().unwrap_or(policy.wind_speed_decimals),
+            ..policy
+        };
+        f.write_str(&policy.wind_speed(*self))
+    }
+}
+
+impl fmt::Display for Precipitation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let policy = FormatPolicy::default();
+        let policy = FormatPolicy {
+            precipitation_decimals: f.precision().unwrap_or(policy.precipitation_decimals),
+            ..policy
+        };
+        f.write_str(&policy.precipitation(*self))
+    }
+}
+
+/// Converts the temperatures and wind speeds of a response between units
+#[derive(Clone, Copy)]
+pub(crate) struct Converter {
+    pub from: Units,
+    pub to: Units,
+}
+
+impl Converter {
+    fn temp(self, temp: &mut f64) {
+        *temp = self.to.from_celsius(self.from.to_celsius(*temp));
+    }
+
+    fn speed(self, speed: &mut f64) {
+        *speed = self
+            .to
+            .from_metres_per_second(self.from.to_metres_per_second(*speed));
+    }
+
+    pub fn weather(self, weather: &mut Weather) {
+        if self.from == self.to {
+            return;
+        }
+
+        weather.current.iter_mut().for_each(|x| self.current(x));
+        weather
+            .hourly
+            .iter_mut()
+            .flatten()
+            .for_each(|x| self.hourly(x));
+        weather
+            .daily
+            .iter_mut()
+            .flatten()
+            .for_each(|x| self.daily(x));
+    }
+
+    fn current(self, x: &mut Current) {
+        self.temp(&mut x.temp);
+        self.temp(&mut x.feels_like);
+        self.temp(&mut x.dew_point);
+        self.speed(&mut x.wind_speed);
+        x.wind_gust.iter_mut().for_each(|x| self.speed(x));
+    }
+
+    fn hourly(self, x: &mut Hourly) {
+        self.temp(&mut x.temp);
+        self.temp(&mut x.feels_like);
+        self.temp(&mut x.dew_point);
+        self.speed(&mut x.wind_speed);
+        x.wind_gust.iter_mut().for_each(|x| self.speed(x));
+    }
+
+    fn daily(self, x: &mut Daily) {
+        let t = &mut x.temp;
+        for temp in [
+            &mut t.morn,
+            &mut t.day,
+            &mut t.eve,
+            &mut t.night,
+            &mut t.min,
+            &mut t.max,
+        ] {
+            self.temp(temp);
+        }
+        let t = &mut x.feels_like;
+        for temp in [&mut t.morn, &mut t.day, &mut t.eve, &mut t.night] {
+            self.temp(temp);
+        }
+        self.temp(&mut x.dew_point);
+        self.speed(&mut x.wind_speed);
+        x.wind_gust.iter_mut().for_each(|x| self.speed(x));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn convert_units() {
+        let temp = Temperature::new(-40.0, Units::Imperial);
+        assert!((temp.celsius() + 40.0).abs() < 1e-9);
+        assert!((temp.kelvin() - 233.15).abs() < 1e-9);
+
+        let wind = WindSpeed::new(10.0, Units::Metric);
+        assert!((wind.kilometres_per_hour() - 36.0).abs() < 1e-9);
+        assert!((wind.miles_per_hour() - 22.369).abs() < 1e-3);
+        assert!(
+            (WindSpeed::new(wind.miles_per_hour(), Units::Imperial).metres_per_second() - 10.0)
+                .abs()
+                < 1e-9
+        );
+
+        assert!((Precipitation(25.4).inches() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn convert_weather() {
+        let weather = crate::fixtures::full();
+        let weather = weather.to_imperial(Units::Metric);
+
+        let current = weather.current.as_ref().unwrap();
+        assert!((current.temp - 81.14).abs() < 1e-9);
+        assert!((current.wind_speed - 12.661).abs() < 1e-3);
+        assert_eq!(10000, current.visibility.unwrap());
+
+        let daily = &weather.daily.as_ref().unwrap()[0];
+        assert!((daily.temp.max - 83.12).abs() < 1e-9);
+
+        let weather = weather.to_standard(Units::Imperial);
+        assert!((weather.current.unwrap().temp - 300.45).abs() < 1e-9);
+    }
+}
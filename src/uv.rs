@@ -0,0 +1,77 @@
+//! UV index categories.
+
+use std::fmt;
+
+/// WHO exposure category of a UV index
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum UvCategory {
+    /// 0 to 2
+    Low,
+
+    /// 3 to 5
+    Moderate,
+
+    /// 6 to 7
+    High,
+
+    /// 8 to 10
+    VeryHigh,
+
+    /// 11 and above
+    Extreme,
+}
+
+impl UvCategory {
+    /// Category of a UV index, which is rounded to the nearest whole number first
+    pub fn from_uvi(uvi: f64) -> Self {
+        match uvi.round() {
+            x if x < 3.0 => Self::Low,
+            x if x < 6.0 => Self::Moderate,
+            x if x < 8.0 => Self::High,
+            x if x < 11.0 => Self::VeryHigh,
+            _ => Self::Extreme,
+        }
+    }
+
+    /// Name of the category, such as `"Very high"`
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Low => "Low",
+            Self::Moderate => "Moderate",
+            Self::High => "High",
+            Self::VeryHigh => "Very high",
+            Self::Extreme => "Extreme",
+        }
+    }
+
+    /// WHO colour code of the category, as a hex RGB string
+    pub fn colour(self) -> &'static str {
+        match self {
+            Self::Low => "#289500",
+            Self::Moderate => "#F7E400",
+            Self::High => "#F85900",
+            Self::VeryHigh => "#D8001D",
+            Self::Extreme => "#6B49C8",
+        }
+    }
+}
+
+impl fmt::Display for UvCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.name().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn category_from_uvi() {
+        assert_eq!(UvCategory::Low, UvCategory::from_uvi(0.0));
+        assert_eq!(UvCategory::Moderate, UvCategory::from_uvi(2.5));
+        assert_eq!(UvCategory::High, UvCategory::from_uvi(7.1));
+        assert_eq!(UvCategory::VeryHigh, UvCategory::from_uvi(7.9));
+        assert_eq!(UvCategory::Extreme, UvCategory::from_uvi(11.0));
+    }
+}
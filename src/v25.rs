@@ -0,0 +1,131 @@
+//! Compatibility with archived One Call 2.5 payloads
+//!
+//! The 2.5 API returned the same overall shape as 3.0, but older responses omit fields that
+//! were only added later:
+//!
+//! - hourly `uvi` and hourly/daily `pop` (left as `None`)
+//! - alert `tags` (filled with an empty list)
+//! - daily `summary`, which is only available in 3.0 (left as `None`)
+//!
+//! The shared models already accept missing optional fields, so only alerts need their own
+//! shape here. Use [`deserialize`] in place of [`Weather`](crate::Weather)'s own `Deserialize`
+//! implementation:
+//!
+//! ```
+//! # let json = include_str!("../fixtures/onecall_v25.json");
+//! let weather = owm_rs::v25::deserialize(&mut serde_json::Deserializer::from_str(json))?;
+//! # Ok::<(), serde_json::Error>(())
+//! ```
+
+use crate::{ts_seconds, Current, Daily, Hourly, Minutely};
+use jiff::Zoned;
+use serde::{Deserialize, Deserializer};
+
+/// Deserialize a One Call 2.5 response into a [`Weather`](crate::Weather)
+pub fn deserialize<'de, D>(d: D) -> Result<crate::Weather, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    Weather::deserialize(d).map(Into::into)
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+struct Weather {
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    lat: f64,
+    #[serde(deserialize_with = "crate::lenient::deserialize")]
+    lon: f64,
+    timezone: String,
+    #[serde(deserialize_with = "crate::integer::deserialize")]
+    timezone_offset: i32,
+    current: Option<Current>,
+    minutely: Option<Vec<Minutely>>,
+    hourly: Option<Vec<Hourly>>,
+    daily: Option<Vec<Daily>>,
+    alerts: Option<Vec<Alert>>,
+
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+#[cfg_attr(
+    all(feature = "strict", not(feature = "extra-fields")),
+    serde(deny_unknown_fields)
+)]
+struct Alert {
+    sender_name: String,
+    event: String,
+    #[serde(with = "ts_seconds")]
+    start: Zoned,
+    #[serde(with = "ts_seconds")]
+    end: Zoned,
+    description: String,
+    #[serde(default)]
+    tags: Vec<String>,
+
+    #[cfg(feature = "extra-fields")]
+    #[serde(flatten)]
+    extra: serde_json::Map<String, serde_json::Value>,
+}
+
+impl From<Weather> for crate::Weather {
+    fn from(w: Weather) -> Self {
+        Self {
+            lat: w.lat,
+            lon: w.lon,
+            timezone: w.timezone,
+            timezone_offset: w.timezone_offset,
+            current: w.current,
+            minutely: w.minutely,
+            hourly: w.hourly,
+            daily: w.daily,
+            alerts: w.alerts.map(|x| x.into_iter().map(Into::into).collect()),
+            #[cfg(feature = "extra-fields")]
+            extra: w.extra,
+        }
+    }
+}
+
+impl From<Alert> for crate::Alert {
+    fn from(a: Alert) -> Self {
+        Self {
+            sender_name: a.sender_name,
+            event: a.event,
+            start: a.start,
+            end: a.end,
+            description: a.description,
+            tags: a.tags,
+            #[cfg(feature = "extra-fields")]
+            extra: a.extra,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(json: &str) -> crate::Weather {
+        deserialize(&mut serde_json::Deserializer::from_str(json)).unwrap()
+    }
+
+    #[test]
+    fn parse_v25_fixtures() {
+        let weather = parse(include_str!("../fixtures/onecall_v25.json"));
+        let hourly = weather.hourly.unwrap();
+        assert_eq!(None, hourly[0].uvi);
+        assert_eq!(Some(0.12), hourly[1].pop);
+        assert!(weather.alerts.unwrap()[0].tags.is_empty());
+        assert!(weather.daily.unwrap()[0].summary.is_none());
+
+        let weather = parse(include_str!("../fixtures/onecall_v25_no_alerts.json"));
+        assert!(weather.alerts.is_none());
+        assert_eq!(Some(0.5), weather.hourly.unwrap()[1].uvi);
+    }
+}
@@ -0,0 +1,237 @@
+//! Consistency checks for responses from untrusted sources, such as third-party caches.
+
+use crate::{Current, Daily, Hourly, Precipitation, Weather};
+use jiff::Zoned;
+
+/// Part of a [`Weather`] response
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Series {
+    Current,
+    Minutely,
+    Hourly,
+    Daily,
+    Alerts,
+}
+
+/// What's wrong with an entry
+#[derive(Debug, PartialEq)]
+pub enum IssueKind {
+    /// Humidity above 100%
+    Humidity(u8),
+
+    /// Cloudiness above 100%
+    Clouds(u8),
+
+    /// Wind direction above 360 degrees
+    WindDirection(u16),
+
+    /// Negative rain, snow, or minutely precipitation
+    NegativePrecipitation(f64),
+
+    /// Probability of precipitation outside of 0 to 1
+    Pop(f64),
+
+    /// Sunset before sunrise
+    SunsetBeforeSunrise,
+
+    /// Alert ending before it starts
+    EndBeforeStart,
+
+    /// Entry earlier than the one before it
+    OutOfOrder,
+
+    /// Entry at the same time as the one before it
+    Duplicate,
+}
+
+/// An impossible value found by [`Weather::validate`]
+#[derive(Debug, PartialEq)]
+pub struct ValidationIssue {
+    pub series: Series,
+
+    /// Position of the entry in its series. Always 0 for the current entry.
+    pub index: usize,
+
+    /// Time of the entry (start time for alerts)
+    pub dt: Zoned,
+
+    pub kind: IssueKind,
+}
+
+pub(crate) fn validate(weather: &Weather) -> Vec<ValidationIssue> {
+    let mut issues = Vec::new();
+    let mut push = |series, index, dt: &Zoned, kind| {
+        issues.push(ValidationIssue {
+            series,
+            index,
+            dt: dt.clone(),
+            kind,
+        });
+    };
+
+    if let Some(x) = &weather.current {
+        for kind in current_issues(x) {
+            push(Series::Current, 0, &x.dt, kind);
+        }
+    }
+
+    let minutely = weather.minutely.as_deref().unwrap_or_default();
+    for (i, x) in minutely.iter().enumerate() {
+        if x.precipitation < 0.0 {
+            let kind = IssueKind::NegativePrecipitation(x.precipitation);
+            push(Series::Minutely, i, &x.dt, kind);
+        }
+    }
+
+    let hourly = weather.hourly.as_deref().unwrap_or_default();
+    for (i, x) in hourly.iter().enumerate() {
+        for kind in hourly_issues(x) {
+            push(Series::Hourly, i, &x.dt, kind);
+        }
+    }
+
+    let daily = weather.daily.as_deref().unwrap_or_default();
+    for (i, x) in daily.iter().enumerate() {
+        for kind in daily_issues(x) {
+            push(Series::Daily, i, &x.dt, kind);
+        }
+    }
+
+    for (i, x) in weather.alerts.iter().flatten().enumerate() {
+        if x.end < x.start {
+            push(Series::Alerts, i, &x.start, IssueKind::EndBeforeStart);
+        }
+    }
+
+    let series = [
+        (Series::Minutely, minutely.iter().map(|x| &x.dt).collect()),
+        (Series::Hourly, hourly.iter().map(|x| &x.dt).collect()),
+        (
+            Series::Daily,
+            daily.iter().map(|x| &x.dt).collect::<Vec<_>>(),
+        ),
+    ];
+    for (series, times) in series {
+        for (i, pair) in times.windows(2).enumerate() {
+            if pair[1] < pair[0] {
+                push(series, i + 1, pair[1], IssueKind::OutOfOrder);
+            } else if pair[1] == pair[0] {
+                push(series, i + 1, pair[1], IssueKind::Duplicate);
+            }
+        }
+    }
+
+    issues
+}
+
+fn common_issues(
+    humidity: u8,
+    clouds: u8,
+    wind_deg: u16,
+    precipitation: [f64; 2],
+) -> Vec<IssueKind> {
+    let mut issues = Vec::new();
+    if humidity > 100 {
+        issues.push(IssueKind::Humidity(humidity));
+    }
+    if clouds > 100 {
+        issues.push(IssueKind::Clouds(clouds));
+    }
+    if wind_deg > 360 {
+        issues.push(IssueKind::WindDirection(wind_deg));
+    }
+    issues.extend(
+        precipitation
+            .into_iter()
+            .filter(|x| *x < 0.0)
+            .map(IssueKind::NegativePrecipitation),
+    );
+    issues
+}
+
+fn volume(x: &Option<Precipitation>) -> f64 {
+    x.as_ref().map_or(0.0, |x| x.volume().0)
+}
+
+fn sun_issues(sunrise: &Option<Zoned>, sunset: &Option<Zoned>) -> Option<IssueKind> {
+    match (sunrise, sunset) {
+        (Some(sunrise), Some(sunset)) if sunset < sunrise => Some(IssueKind::SunsetBeforeSunrise),
+        _ => None,
+    }
+}
+
+fn pop_issues(pop: f64) -> Option<IssueKind> {
+    (!(0.0..=1.0).contains(&pop)).then_some(IssueKind::Pop(pop))
+}
+
+fn current_issues(x: &Current) -> Vec<IssueKind> {
+    let mut issues = common_issues(
+        x.humidity,
+        x.clouds,
+        x.wind_deg,
+        [volume(&x.rain), volume(&x.snow)],
+    );
+    issues.extend(sun_issues(&x.sunrise, &x.sunset));
+    issues
+}
+
+fn hourly_issues(x: &Hourly) -> Vec<IssueKind> {
+    let mut issues = common_issues(
+        x.humidity,
+        x.clouds,
+        x.wind_deg,
+        [volume(&x.rain), volume(&x.snow)],
+    );
+    issues.extend(x.pop.and_then(pop_issues));
+    issues
+}
+
+fn daily_issues(x: &Daily) -> Vec<IssueKind> {
+    let mut issues = common_issues(
+        x.humidity,
+        x.clouds,
+        x.wind_deg,
+        [x.rain.unwrap_or_default(), x.snow.unwrap_or_default()],
+    );
+    issues.extend(x.pop.and_then(pop_issues));
+    issues.extend(sun_issues(&x.sunrise, &x.sunset));
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn find_issues() {
+        let mut weather = crate::fixtures::full();
+        assert_eq!(Vec::<ValidationIssue>::new(), weather.validate());
+
+        let hourly = weather.hourly.as_mut().unwrap();
+        hourly[1].humidity = 104;
+        hourly[1].pop = Some(-0.1);
+        hourly[3].dt = hourly[2].dt.clone();
+        let daily = weather.daily.as_mut().unwrap();
+        daily[0].sunset = daily[0]
+            .sunrise
+            .as_ref()
+            .map(|x| x - jiff::Span::new().hours(1));
+        daily[2].rain = Some(-1.0);
+
+        let issues: Vec<_> = weather
+            .validate()
+            .into_iter()
+            .map(|x| (x.series, x.index, x.kind))
+            .collect();
+        assert_eq!(
+            vec![
+                (Series::Hourly, 1, IssueKind::Humidity(104)),
+                (Series::Hourly, 1, IssueKind::Pop(-0.1)),
+                (Series::Daily, 0, IssueKind::SunsetBeforeSunrise),
+                (Series::Daily, 2, IssueKind::NegativePrecipitation(-1.0)),
+                (Series::Hourly, 3, IssueKind::Duplicate),
+            ],
+            issues
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Wind direction and strength classifications.
+
+use crate::units::{Units, WindSpeed};
+use std::fmt;
+
+/// A 16-point compass direction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompassDirection {
+    N,
+    NNE,
+    NE,
+    ENE,
+    E,
+    ESE,
+    SE,
+    SSE,
+    S,
+    SSW,
+    SW,
+    WSW,
+    W,
+    WNW,
+    NW,
+    NNW,
+}
+
+impl CompassDirection {
+    const ALL: [Self; 16] = [
+        Self::N,
+        Self::NNE,
+        Self::NE,
+        Self::ENE,
+        Self::E,
+        Self::ESE,
+        Self::SE,
+        Self::SSE,
+        Self::S,
+        Self::SSW,
+        Self::SW,
+        Self::WSW,
+        Self::W,
+        Self::WNW,
+        Self::NW,
+        Self::NNW,
+    ];
+
+    /// The closest compass point to a direction in degrees (meteorological), wrapping around 360
+    pub fn from_degrees(degrees: f64) -> Self {
+        let index = (degrees.rem_euclid(360.0) / 22.5).round() as usize % 16;
+        Self::ALL[index]
+    }
+
+    /// Abbreviated name, such as `"NNW"`
+    pub fn abbreviation(self) -> &'static str {
+        match self {
+            Self::N => "N",
+            Self::NNE => "NNE",
+            Self::NE => "NE",
+            Self::ENE => "ENE",
+            Self::E => "E",
+            Self::ESE => "ESE",
+            Self::SE => "SE",
+            Self::SSE => "SSE",
+            Self::S => "S",
+            Self::SSW => "SSW",
+            Self::SW => "SW",
+            Self::WSW => "WSW",
+            Self::W => "W",
+            Self::WNW => "WNW",
+            Self::NW => "NW",
+            Self::NNW => "NNW",
+        }
+    }
+}
+
+impl From<u16> for CompassDirection {
+    fn from(degrees: u16) -> Self {
+        Self::from_degrees(f64::from(degrees))
+    }
+}
+
+impl fmt::Display for CompassDirection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.abbreviation().fmt(f)
+    }
+}
+
+/// Beaufort wind force scale
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Beaufort {
+    Calm,
+    LightAir,
+    LightBreeze,
+    GentleBreeze,
+    ModerateBreeze,
+    FreshBreeze,
+    StrongBreeze,
+    NearGale,
+    Gale,
+    StrongGale,
+    Storm,
+    ViolentStorm,
+    Hurricane,
+}
+
+impl Beaufort {
+    /// Lower bound of each force above calm, metres per second
+    const LIMITS: [f64; 12] = [
+        0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7,
+    ];
+
+    const ALL: [Self; 13] = [
+        Self::Calm,
+        Self::LightAir,
+        Self::LightBreeze,
+        Self::GentleBreeze,
+        Self::ModerateBreeze,
+        Self::FreshBreeze,
+        Self::StrongBreeze,
+        Self::NearGale,
+        Self::Gale,
+        Self::StrongGale,
+        Self::Storm,
+        Self::ViolentStorm,
+        Self::Hurricane,
+    ];
+
+    pub fn from_metres_per_second(speed: f64) -> Self {
+        let force = Self::LIMITS.iter().take_while(|&&x| speed >= x).count();
+        Self::ALL[force]
+    }
+
+    pub fn from_miles_per_hour(speed: f64) -> Self {
+        Self::from_metres_per_second(Units::Imperial.to_metres_per_second(speed))
+    }
+
+    /// Force number, from 0 to 12
+    pub fn number(self) -> u8 {
+        self as u8
+    }
+
+    /// Plain English description, such as `"Moderate breeze"`
+    pub fn description(self) -> &'static str {
+        match self {
+            Self::Calm => "Calm",
+            Self::LightAir => "Light air",
+            Self::LightBreeze => "Light breeze",
+            Self::GentleBreeze => "Gentle breeze",
+            Self::ModerateBreeze => "Moderate breeze",
+            Self::FreshBreeze => "Fresh breeze",
+            Self::StrongBreeze => "Strong breeze",
+            Self::NearGale => "Near gale",
+            Self::Gale => "Gale",
+            Self::StrongGale => "Strong gale",
+            Self::Storm => "Storm",
+            Self::ViolentStorm => "Violent storm",
+            Self::Hurricane => "Hurricane force",
+        }
+    }
+}
+
+impl From<WindSpeed> for Beaufort {
+    fn from(speed: WindSpeed) -> Self {
+        Self::from_metres_per_second(speed.metres_per_second())
+    }
+}
+
+impl fmt::Display for Beaufort {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.description().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compass_direction() {
+        assert_eq!(CompassDirection::N, 0.into());
+        assert_eq!(CompassDirection::NW, 318.into());
+        assert_eq!(CompassDirection::N, 355.into());
+        assert_eq!(CompassDirection::N, 360.into());
+        assert_eq!(
+            CompassDirection::SSW,
+            CompassDirection::from_degrees(-158.0)
+        );
+        assert_eq!("WNW", CompassDirection::from(292).to_string());
+    }
+
+    #[test]
+    fn beaufort() {
+        assert_eq!(Beaufort::Calm, Beaufort::from_metres_per_second(0.0));
+        assert_eq!(
+            Beaufort::ModerateBreeze,
+            Beaufort::from_metres_per_second(5.66)
+        );
+        assert_eq!(Beaufort::FreshBreeze, Beaufort::from_miles_per_hour(20.0));
+        assert_eq!(Beaufort::Hurricane, Beaufort::from_metres_per_second(40.0));
+        assert_eq!(4, Beaufort::ModerateBreeze.number());
+        assert_eq!(Beaufort::Gale, WindSpeed::new(40.0, Units::Imperial).into());
+    }
+}